@@ -0,0 +1,143 @@
+//! Links every solution in `src/bin` into the main binary with the `in-process` feature, see `template::registry`,
+//! and generates the tests of their annotated example files, see `template::examples`.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut days: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension()? != "rs" {
+                        return None;
+                    }
+                    let stem = path.file_stem()?.to_str()?;
+                    let module = module_name(stem)?;
+                    Some((module, path.to_string_lossy().into_owned()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort();
+
+    let mut out = String::new();
+
+    for (module, path) in &days {
+        out.push_str(&format!("#[path = {path:?}]\nmod {module};\n"));
+    }
+
+    out.push_str("\npub static SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for (module, _) in &days {
+        out.push_str(&format!("    {module}::SOLUTION,\n"));
+    }
+    out.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), out).unwrap();
+
+    let tests_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&tests_dir).unwrap();
+
+    for (module, _) in &days {
+        let (year, day) = puzzle(module);
+        let examples_dir = match &year {
+            Some(year) => PathBuf::from("data").join(year).join("examples"),
+            None => PathBuf::from("data").join("examples"),
+        };
+        println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+        let tests = example_tests(&examples_dir, &day);
+        let day = day.parse::<u8>().unwrap();
+        let file_name = match &year {
+            Some(year) => format!("{year}-{day}.rs"),
+            None => format!("{day}.rs"),
+        };
+        fs::write(tests_dir.join(file_name), tests).unwrap();
+    }
+}
+
+/// Module name for a solution bin, `NN` becomes `day_NN` and `YYYY-NN` becomes `day_YYYY_NN`.
+fn module_name(stem: &str) -> Option<String> {
+    let is_day = |s: &str| s.len() == 2 && s.parse::<u8>().is_ok_and(|day| (1..=25).contains(&day));
+    let is_year = |s: &str| s.len() == 4 && s.parse::<u16>().is_ok_and(|year| year >= 2015);
+
+    match stem.split_once('-') {
+        Some((year, day)) if is_year(year) && is_day(day) => Some(format!("day_{year}_{day}")),
+        None if is_day(stem) => Some(format!("day_{stem}")),
+        _ => None,
+    }
+}
+
+/// Year and day of a solution module, `day_YYYY_NN` becomes `(Some(YYYY), NN)`.
+fn puzzle(module: &str) -> (Option<String>, String) {
+    let name = module.trim_start_matches("day_");
+    match name.split_once('_') {
+        Some((year, day)) => (Some(year.to_string()), day.to_string()),
+        None => (None, name.to_string()),
+    }
+}
+
+/// One test per example file of a day (`NN.txt` or `NN-<name>.txt`) and part with an expected answer in its sidecar.
+fn example_tests(examples_dir: &Path, day: &str) -> String {
+    let mut files: Vec<PathBuf> = fs::read_dir(examples_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                        return false;
+                    };
+                    path.extension().is_some_and(|ext| ext == "txt")
+                        && (stem == day
+                            || stem.strip_prefix(day).is_some_and(|s| s.starts_with('-')))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    files.sort();
+
+    let mut out = String::new();
+
+    for path in files {
+        let sidecar = fs::read_to_string(path.with_extension("toml")).unwrap_or_default();
+        let stem = path.file_stem().unwrap().to_string_lossy();
+        let test_name: String = stem
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        for (part, name) in [(1, "part_one"), (2, "part_two")] {
+            if !has_key(&sidecar, &format!("expected_{name}")) {
+                continue;
+            }
+
+            out.push_str(&format!(
+                "#[test]\nfn example_{test_name}_{name}() {{\n    \
+                 advent_of_code::template::examples::check(super::run_parts, {:?}, {part});\n}}\n\n",
+                path.to_string_lossy()
+            ));
+        }
+    }
+
+    out
+}
+
+/// Whether a sidecar sets a key, ignoring comments.
+fn has_key(sidecar: &str, key: &str) -> bool {
+    sidecar.lines().any(|line| {
+        let line = line.trim();
+        !line.starts_with('#') && line.split_once('=').is_some_and(|(k, _)| k.trim() == key)
+    })
+}
@@ -20,7 +20,7 @@ fn blink_stone(
         return memo[&(s, blink_count)];
     } else if blink_count == 1 {
         let stone_as_string = s.to_string();
-        if stone_as_string.len() % 2 == 0 {
+        if stone_as_string.len().is_multiple_of(2) {
             result = 2;
         } else {
             result = 1;
@@ -29,7 +29,7 @@ fn blink_stone(
         result = blink_stone(1, blink_count - 1, memo, stats);
     } else {
         let stone_as_string = s.to_string();
-        if stone_as_string.len() % 2 == 0 {
+        if stone_as_string.len().is_multiple_of(2) {
             let (lhs, rhs) = stone_as_string.split_at(stone_as_string.len() / 2);
             result = blink_stone(lhs.parse().unwrap(), blink_count - 1, memo, stats)
                 + blink_stone(rhs.parse().unwrap(), blink_count - 1, memo, stats);
@@ -336,10 +336,5 @@ mod tests {
             "examples", DAY, 2,
         ));
         assert_eq!(result, Some(80));
-
-        // let result = part_two(&advent_of_code::template::read_file_part(
-        //     "examples", DAY, 1,
-        // ));
-        // assert_eq!(result, Some(368));
     }
 }
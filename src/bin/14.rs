@@ -1,9 +1,25 @@
 use std::{fs, path::Path};
 
+use advent_of_code::template::params::{FromParams, ParamsError};
 use image::{GrayImage, Luma};
 use regex::Regex;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(14, params = Params);
+
+/// Size of the space the robots move in. The example uses a smaller one, see `data/examples/14.toml`.
+pub struct Params {
+    width: i32,
+    height: i32,
+}
+
+impl FromParams for Params {
+    fn from_params(params: &advent_of_code::template::params::Params) -> Result<Self, ParamsError> {
+        Ok(Self {
+            width: params.get_or("width", 101)?,
+            height: params.get_or("height", 103)?,
+        })
+    }
+}
 
 struct Bot {
     x: i32,
@@ -12,16 +28,11 @@ struct Bot {
     vy: i32,
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &str, params: &Params) -> Option<u64> {
     let re = Regex::new(r"p=(\d*),(\d*) v=(-?\d*),(-?\d*)").unwrap();
 
-    // Sample & test
-    let width = 11;
-    let height = 7;
-
-    // Real
-    // let width = 101;
-    // let height = 103;
+    let width = params.width;
+    let height = params.height;
 
     let mut quad = [0u64; 4];
 
@@ -51,12 +62,10 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(quad[0] * quad[1] * quad[2] * quad[3])
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str, params: &Params) -> Option<u32> {
     let re = Regex::new(r"p=(\d*),(\d*) v=(-?\d*),(-?\d*)").unwrap();
 
-    // Real
-    const WIDTH: usize = 101;
-    const HEIGHT: usize = 103;
+    let (width, height) = (params.width, params.height);
 
     let mut bots = Vec::new();
 
@@ -83,11 +92,11 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     // make iteration to 10k to output answer
     for i in 0..1 {
-        let mut img = GrayImage::new(WIDTH as u32, HEIGHT as u32);
+        let mut img = GrayImage::new(width as u32, height as u32);
 
         for b in &mut bots {
-            let x = (WIDTH as i32 + b.x + b.vx) % WIDTH as i32;
-            let y = (HEIGHT as i32 + b.y + b.vy) % HEIGHT as i32;
+            let x = (width + b.x + b.vx) % width;
+            let y = (height + b.y + b.vy) % height;
             img.put_pixel(x as u32, y as u32, Luma([255]));
             b.x = x;
             b.y = y;
@@ -103,16 +112,23 @@ pub fn part_two(input: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::params::read_params;
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", DAY),
+            &read_params("examples", DAY),
+        );
         assert_eq!(result, Some(12));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", DAY),
+            &read_params("examples", DAY),
+        );
         assert_eq!(result, None);
     }
 }
@@ -213,7 +213,7 @@ pub fn part_two(input: &str) -> Option<u32> {
             visits.insert((pos, dir), score);
             queue.push((pos, dir, score, trail));
         }
-        queue.sort_by(|a, b| b.2.cmp(&a.2));
+        queue.sort_by_key(|a| std::cmp::Reverse(a.2));
     }
     Some(sits.len().try_into().unwrap())
 }
@@ -1,31 +1,29 @@
 use core::fmt;
 use std::collections::{HashMap, HashSet};
 
+use advent_of_code::template::params::{FromParams, ParamsError};
 use regex::Regex;
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(18, params = Params);
 
-fn get_world_size() -> (usize, usize) {
-    if cfg!(test) {
-        (7, 7)
-    } else {
-        (71, 71)
-    }
-}
-
-fn get_exit() -> Position {
-    if cfg!(test) {
-        Position { x: 6, y: 6 }
-    } else {
-        Position { x: 70, y: 70 }
-    }
+/// The example uses a smaller grid and fewer bytes, see `data/examples/18.toml`.
+pub struct Params {
+    world_size: (usize, usize),
+    exit: Position,
+    bytes: usize,
 }
 
-fn get_bytes() -> usize {
-    if cfg!(test) {
-        12
-    } else {
-        1024
+impl FromParams for Params {
+    fn from_params(params: &advent_of_code::template::params::Params) -> Result<Self, ParamsError> {
+        let size: usize = params.get_or("size", 71)?;
+        Ok(Self {
+            world_size: (size, size),
+            exit: Position {
+                x: size - 1,
+                y: size - 1,
+            },
+            bytes: params.get_or("bytes", 1024)?,
+        })
     }
 }
 
@@ -54,7 +52,7 @@ impl fmt::Display for Position {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str, params: &Params) -> Option<u32> {
     let re = Regex::new(r"(\d*),(\d*)").unwrap();
     let mut bytes = Vec::new();
     for [x, y] in re
@@ -64,11 +62,11 @@ pub fn part_one(input: &str) -> Option<u32> {
         bytes.push(Position { x, y });
     }
 
-    let _bytes: HashSet<_> = bytes[0..get_bytes()].iter().cloned().collect();
+    let _bytes: HashSet<_> = bytes[0..params.bytes].iter().cloned().collect();
 
-    let exit = get_exit();
+    let exit = params.exit;
 
-    let world_size = get_world_size();
+    let world_size = params.world_size;
 
     let start_pos = Position { x: 0, y: 0 };
     let mut visits = HashMap::new();
@@ -99,7 +97,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     visits.get(&exit).cloned()
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(input: &str, params: &Params) -> Option<String> {
     let re = Regex::new(r"(\d*),(\d*)").unwrap();
     let mut bytes = Vec::new();
     for [x, y] in re
@@ -109,13 +107,13 @@ pub fn part_two(input: &str) -> Option<String> {
         bytes.push(Position { x, y });
     }
 
-    let mut wall_bytes: HashSet<_> = bytes[0..get_bytes()].iter().cloned().collect();
+    let mut wall_bytes: HashSet<_> = bytes[0..params.bytes].iter().cloned().collect();
 
-    let exit = get_exit();
+    let exit = params.exit;
 
-    let world_size = get_world_size();
+    let world_size = params.world_size;
 
-    'falling_bytes: for b in &bytes[get_bytes()..] {
+    'falling_bytes: for b in &bytes[params.bytes..] {
         wall_bytes.insert(*b);
         let start_pos = Position { x: 0, y: 0 };
         let mut visits = HashMap::new();
@@ -151,16 +149,23 @@ pub fn part_two(input: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::params::read_params;
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", DAY),
+            &read_params("examples", DAY),
+        );
         assert_eq!(result, Some(22));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", DAY),
+            &read_params("examples", DAY),
+        );
         assert_eq!(result, Some("6,1".to_string()));
     }
 }
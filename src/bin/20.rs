@@ -1,9 +1,26 @@
 use core::fmt;
 use std::collections::{HashMap, HashSet};
 
+use advent_of_code::template::params::{FromParams, ParamsError};
 use itertools::Itertools;
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(20, params = Params);
+
+/// Minimum number of picoseconds a cheat must save to be counted.
+/// The example counts smaller savings, see `data/examples/20.toml`.
+pub struct Params {
+    part_one_saving: usize,
+    part_two_saving: usize,
+}
+
+impl FromParams for Params {
+    fn from_params(params: &advent_of_code::template::params::Params) -> Result<Self, ParamsError> {
+        Ok(Self {
+            part_one_saving: params.get_or("part_one_saving", 100)?,
+            part_two_saving: params.get_or("part_two_saving", 100)?,
+        })
+    }
+}
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 struct Position {
@@ -50,10 +67,8 @@ fn get_cheats(
     shortcuts
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let _ = input;
-
-    let save_condition = if cfg!(test) { 20 } else { 100 };
+pub fn part_one(input: &str, params: &Params) -> Option<u32> {
+    let save_condition = params.part_one_saving;
 
     let mut start_pos = Position { x: 0, y: 0 };
     let mut exit_pos = Position { x: 0, y: 0 };
@@ -121,10 +136,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let _ = input;
-
-    let save_condition = if cfg!(test) { 70 } else { 100 };
+pub fn part_two(input: &str, params: &Params) -> Option<u32> {
+    let save_condition = params.part_two_saving;
 
     let mut start_pos = Position { x: 0, y: 0 };
     let mut exit_pos = Position { x: 0, y: 0 };
@@ -206,16 +219,23 @@ pub fn part_two(input: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::params::read_params;
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", DAY),
+            &read_params("examples", DAY),
+        );
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", DAY),
+            &read_params("examples", DAY),
+        );
         assert_eq!(result, Some(41));
     }
 }
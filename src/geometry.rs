@@ -0,0 +1,498 @@
+/// 2D points, vectors and directions on a grid where `x` grows to the right and `y` grows downwards.
+///
+/// Points are usually unsigned positions, e.g. `Point<usize>` in a [`crate::grid::Grid`], and vectors signed offsets.
+/// Checked conversions move between the two without wrapping around:
+///
+/// ```ignore
+/// let next = position.checked_add(Direction::Up.into())?;
+/// let signed: Point<i64> = position.try_cast()?;
+/// ```
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A point, e.g. the position of a cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A vector, e.g. the offset between two points or a step in a direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Convert the coordinates to a type that holds every value, e.g. `u32` to `i64`.
+    pub fn cast<U: From<T>>(self) -> Point<U> {
+        Point::new(U::from(self.x), U::from(self.y))
+    }
+
+    /// Convert the coordinates to another type, `None` if they do not fit, e.g. negative coordinates to `usize`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Add<Output = T>> Point<T> {
+    /// Number of orthogonal steps between two points.
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Number of steps between two points when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl Point<usize> {
+    /// The point moved by a signed vector, `None` if a coordinate would become negative or overflow.
+    pub fn checked_add(self, v: Vec2<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(v.x)?,
+            self.y.checked_add_signed(v.y)?,
+        ))
+    }
+
+    /// The neighbour in a direction, `None` if it would have a negative coordinate.
+    pub fn step(self, direction: impl Into<Vec2<isize>>) -> Option<Self> {
+        self.checked_add(direction.into())
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Convert the components to a type that holds every value, e.g. `i32` to `i64`.
+    pub fn cast<U: From<T>>(self) -> Vec2<U> {
+        Vec2::new(U::from(self.x), U::from(self.y))
+    }
+
+    /// Convert the components to another type, `None` if they do not fit.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vec2<U>> {
+        Some(Vec2::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T: Neg<Output = T>> Vec2<T> {
+    /// The vector turned by a quarter turn clockwise, e.g. up to right.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// The vector turned by a quarter turn counter-clockwise, e.g. up to left.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl<T: Copy + Ord + Default + Sub<Output = T> + Add<Output = T>> Vec2<T> {
+    /// Length in orthogonal steps.
+    pub fn manhattan(self) -> T {
+        let zero = T::default();
+        abs_diff(self.x, zero) + abs_diff(self.y, zero)
+    }
+
+    /// Length in steps when diagonal steps are allowed.
+    pub fn chebyshev(self) -> T {
+        let zero = T::default();
+        abs_diff(self.x, zero).max(abs_diff(self.y, zero))
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// Formatted as `x,y`, like coordinates in puzzle inputs.
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl<T: Add<Output = T>> Add<Vec2<T>> for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vec2<T>> for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// The vector from `rhs` to `self`.
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vec2<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vec2<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four orthogonal directions. North is up, i.e. towards smaller `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction after a quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// A single step in the direction.
+    pub fn offset(self) -> Vec2<isize> {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+}
+
+impl From<Direction> for Vec2<isize> {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+/// Arrows `^>v<` and compass points `NESW`.
+impl TryFrom<char> for Direction {
+    type Error = DirectionFromStrError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' => Ok(Direction::Up),
+            '>' | 'E' => Ok(Direction::Right),
+            'v' | 'S' => Ok(Direction::Down),
+            '<' | 'W' => Ok(Direction::Left),
+            _ => Err(DirectionFromStrError),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = DirectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(DirectionFromStrError),
+        }
+    }
+}
+
+/// Formatted as an arrow, e.g. `^`.
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arrow = match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+        write!(f, "{arrow}")
+    }
+}
+
+/// An error which can be returned when parsing a [`Direction`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct DirectionFromStrError;
+
+impl Error for DirectionFromStrError {}
+
+impl Display for DirectionFromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expecting a direction, one of ^>v< or NESW")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the eight directions including diagonals, clockwise from north (up).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /// The direction after an eighth turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction after an eighth turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+
+    /// A single step in the direction.
+    pub fn offset(self) -> Vec2<isize> {
+        let (x, y) = match self {
+            Direction8::N => (0, -1),
+            Direction8::NE => (1, -1),
+            Direction8::E => (1, 0),
+            Direction8::SE => (1, 1),
+            Direction8::S => (0, 1),
+            Direction8::SW => (-1, 1),
+            Direction8::W => (-1, 0),
+            Direction8::NW => (-1, -1),
+        };
+        Vec2::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Right => Direction8::E,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+impl From<Direction8> for Vec2<isize> {
+    fn from(direction: Direction8) -> Self {
+        direction.offset()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Direction8, DirectionFromStrError, Point, Vec2};
+
+    #[test]
+    fn does_arithmetic() {
+        let a = Point::new(3_i64, 4);
+        let b = Point::new(1_i64, 7);
+        assert_eq!(a - b, Vec2::new(2, -3));
+        assert_eq!(b + (a - b), a);
+        assert_eq!(a + Vec2::new(1, 1) * 2, Point::new(5, 6));
+        assert_eq!(-Vec2::new(1, -2), Vec2::new(-1, 2));
+
+        let mut c = a;
+        c += Vec2::new(-3, -4);
+        assert_eq!(c, Point::default());
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Point::new(3_usize, 4);
+        let b = Point::new(1_usize, 7);
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(Vec2::new(-2, 3).manhattan(), 5);
+        assert_eq!(Vec2::new(-2, 3).chebyshev(), 3);
+    }
+
+    #[test]
+    fn converts_checked() {
+        let origin = Point::new(0_usize, 0);
+        assert_eq!(origin.step(Direction::Up), None);
+        assert_eq!(origin.step(Direction8::SE), Some(Point::new(1, 1)));
+        assert_eq!(Point::new(1_u32, 2).cast::<i64>(), Point::new(1, 2));
+        assert_eq!(Point::new(-1_i32, 2).try_cast::<usize>(), None);
+        assert_eq!(
+            Point::new(1_i32, 2).try_cast::<usize>(),
+            Some(Point::new(1, 2))
+        );
+        assert_eq!(Point::new(6, 1).to_string(), "6,1");
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+        assert!(Direction8::N.turn_left().is_diagonal());
+
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_right(),
+                direction.turn_right().offset()
+            );
+            assert_eq!(
+                direction.offset().rotate_left(),
+                direction.turn_left().offset()
+            );
+            assert_eq!(Direction8::from(direction).offset(), direction.offset());
+        }
+    }
+
+    #[test]
+    fn parses_directions() {
+        assert_eq!("^".parse(), Ok(Direction::Up));
+        assert_eq!("W".parse(), Ok(Direction::Left));
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert_eq!(Direction::try_from('x'), Err(DirectionFromStrError));
+        assert_eq!("^^".parse::<Direction>(), Err(DirectionFromStrError));
+        assert_eq!(Direction::Right.to_string(), ">");
+    }
+}
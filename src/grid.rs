@@ -0,0 +1,541 @@
+/// A dense, row-major grid of cells, e.g. a map parsed from the puzzle input.
+///
+/// ```ignore
+/// let grid: Grid<char> = input.parse()?;
+/// let start = grid.find(&'S').unwrap();
+/// let open = grid.neighbours4(start).filter(|p| grid[*p] != '#').count();
+/// ```
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::geometry::{Direction, Direction8, Point, Vec2};
+
+/// Position of a cell, `x` being the column and `y` the row.
+pub type Position = Point<usize>;
+
+/* -------------------------------------------------------------------------- */
+
+/// Conversion of a single character of the input to a cell, see [`Grid::from_str`].
+pub trait FromChar: Sized {
+    /// The cell of a character, `None` if the character is not valid.
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+/// Digits, e.g. of a height map.
+impl FromChar for u8 {
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).and_then(|d| u8::try_from(d).ok())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// The input has no rows, e.g. it is blank.
+    Empty,
+    /// A row has a different width than the first row.
+    RaggedRow {
+        y: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character that is not a valid cell, see [`FromChar`].
+    InvalidChar { c: char, position: Position },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "the grid is empty."),
+            ParseGridError::RaggedRow { y, expected, found } => write!(
+                f,
+                "row {y} has {found} cells, expected {expected} like the first row."
+            ),
+            ParseGridError::InvalidChar { c, position } => {
+                write!(f, "invalid character '{c}' at {position}.")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A grid always has at least one cell.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` × `height` cells with the same value. Panics if either is zero.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            width > 0 && height > 0,
+            "a grid of {width}x{height} cells is empty"
+        );
+
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// A grid of the given width from its cells in row-major order, `None` if there are none or they do not fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || cells.is_empty() || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+
+        let height = cells.len() / width;
+        Some(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parse a grid of one character per cell, mapping each character with `f`.
+    /// Leading blank lines and trailing whitespace of rows are ignored, a blank line after the first row ends the grid.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseGridError> {
+        Self::try_parse_with(input, |c| Some(f(c)))
+    }
+
+    /// Like [`Grid::parse_with`], but fails with [`ParseGridError::InvalidChar`] for characters mapped to `None`.
+    pub fn try_parse_with(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+
+        let lines = input
+            .lines()
+            .map(str::trim_end)
+            .skip_while(|line| line.is_empty());
+
+        for (y, line) in lines.enumerate() {
+            if y > 0 && line.is_empty() {
+                // a blank line ends the grid, e.g. when it is followed by another section of the input.
+                break;
+            }
+
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or(ParseGridError::InvalidChar {
+                    c,
+                    position: Position::new(x, y),
+                })?;
+                cells.push(cell);
+            }
+
+            let found = cells.len() - row_start;
+            if y == 0 {
+                width = found;
+            } else if found != width {
+                return Err(ParseGridError::RaggedRow {
+                    y,
+                    expected: width,
+                    found,
+                });
+            }
+
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether a position is inside the grid.
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    /// Whether a signed point is inside the grid, e.g. one moved by an offset that might leave it.
+    pub fn contains_signed(&self, point: Point<isize>) -> bool {
+        self.to_position(point).is_some()
+    }
+
+    /// The position of a signed point, `None` if it is outside the grid.
+    pub fn to_position(&self, point: Point<isize>) -> Option<Position> {
+        point.try_cast().filter(|p| self.contains(*p))
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Replace the value of a cell, returning the previous value. `None` if the position is outside the grid.
+    pub fn set(&mut self, position: Position, value: T) -> Option<T> {
+        self.get_mut(position)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.y * self.width + position.x)
+    }
+
+    fn position_of(&self, index: usize) -> Position {
+        Position::new(index % self.width, index / self.width)
+    }
+
+    /// The position moved by an offset or in a direction, `None` if it would leave the grid.
+    pub fn step(&self, position: Position, offset: impl Into<Vec2<isize>>) -> Option<Position> {
+        position
+            .checked_add(offset.into())
+            .filter(|p| self.contains(*p))
+    }
+
+    /// The orthogonal neighbours of a position inside the grid, clockwise from up.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// All neighbours of a position inside the grid, including diagonal ones, clockwise from up.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The positions from a position in a direction until the edge of the grid, starting with the position itself.
+    pub fn ray(
+        &self,
+        start: Position,
+        offset: impl Into<Vec2<isize>>,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let offset = offset.into();
+        std::iter::successors(Some(start).filter(|p| self.contains(*p)), move |p| {
+            self.step(*p, offset)
+        })
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.position_of(i), cell))
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| (Position::new(i % width, i / width), cell))
+    }
+
+    /// Every position, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.cells.len()).map(|i| self.position_of(i))
+    }
+
+    /// The cells of a row. Panics if the row is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of a column, from top to bottom. Panics if the column is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The diagonals from top-left to bottom-right, starting with the one in the bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| Position::new(0, y))
+            .chain((1..self.width).map(|x| Position::new(x, 0)));
+        starts.map(|start| self.ray(start, (1, 1)).map(|p| &self[p]))
+    }
+
+    /// The diagonals from top-right to bottom-left, starting with the one in the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| Position::new(x, 0))
+            .chain((1..self.height).map(|y| Position::new(self.width - 1, y)));
+        starts.map(|start| self.ray(start, (-1, 1)).map(|p| &self[p]))
+    }
+
+    /// The first position with a value, in row-major order.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Every position with a value, in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(p, _)| p)
+    }
+
+    /// The first position of a cell matching a predicate, in row-major order.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.position_of(i))
+    }
+
+    /// A grid of the same size with every cell mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The grid mirrored along its top-left to bottom-right diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |p| Position::new(p.y, p.x))
+    }
+
+    /// The grid rotated by a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |p| {
+            Position::new(p.y, self.height - 1 - p.x)
+        })
+    }
+
+    /// The grid rotated by a quarter turn counter-clockwise.
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |p| {
+            Position::new(self.width - 1 - p.y, p.x)
+        })
+    }
+
+    /// A grid of a new size, taking each cell from the position `source` returns for it.
+    fn rearrange(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[source(Position::new(i % width, i / width))].clone())
+            .collect();
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: FromChar> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_parse_with(s, T::from_char)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {position} is outside the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {position} is outside the {width}x{height} grid"))
+    }
+}
+
+/// One row per line, e.g. to print a map while debugging. Wide cells are not padded.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, ParseGridError, Position};
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(2, 1)], 'f');
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let digits: Grid<u8> = "12\n34".parse().unwrap();
+        assert_eq!(digits.row(1), &[3, 4]);
+
+        let leading_blank: Grid<char> = "\n\nab\ncd\n\nmoves".parse().unwrap();
+        assert_eq!(leading_blank.to_string(), "ab\ncd\n");
+
+        let walls = Grid::parse_with("#.\n.#", |c| c == '#').unwrap();
+        assert_eq!(walls.find_all(&true).count(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_grids() {
+        assert_eq!("".parse::<Grid<char>>(), Err(ParseGridError::Empty));
+        assert_eq!("\n  \n".parse::<Grid<char>>(), Err(ParseGridError::Empty));
+        assert_eq!(Grid::from_vec(2, Vec::<char>::new()), None);
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(ParseGridError::RaggedRow {
+                y: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            "12\n3x".parse::<Grid<u8>>(),
+            Err(ParseGridError::InvalidChar {
+                c: 'x',
+                position: Position::new(1, 1)
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_empty_grids() {
+        let _ = Grid::new(0, 3, '.');
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = grid();
+        let corner: Vec<Position> = grid.neighbours4(Position::new(0, 0)).collect();
+        assert_eq!(corner, vec![Position::new(1, 0), Position::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Position::new(1, 0)).count(), 5);
+        assert_eq!(grid.ray(Position::new(0, 0), (1, 1)).count(), 2);
+        assert_eq!(grid.ray(Position::new(5, 5), (1, 1)).count(), 0);
+    }
+
+    #[test]
+    fn iterates_lines() {
+        let grid = grid();
+        let collect = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+
+        assert_eq!(
+            grid.columns()
+                .map(|mut c| collect(&mut c))
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn finds_values() {
+        let grid = grid();
+        assert_eq!(grid.find(&'e'), Some(Position::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.position(|c| *c > 'c'), Some(Position::new(0, 1)));
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+
+        let mut upper = grid.map(char::to_ascii_uppercase);
+        assert_eq!(upper.set(Position::new(0, 0), 'x'), Some('A'));
+        assert_eq!(upper.to_string(), "xBC\nDEF\n");
+    }
+}
@@ -1,81 +1,233 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, read, readme, scaffold, solve, time, verify,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, PuzzleId};
+
+/// Every solution in `src/bin`, linked in to run days in-process with the `in-process` feature. Generated by `build.rs`.
+/// Left out of test builds to not run solution tests twice, and of dhat and count-alloc builds as each solution declares a global allocator.
+#[cfg(all(
+    feature = "in-process",
+    not(any(test, feature = "dhat-heap", feature = "count-alloc"))
+))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::{solve::WatchMode, time::CompareConfig},
+        inputs::{Example, InputSource},
+        report::{ReportConfig, ReportFormat},
+        runner::{BenchConfig, BuildProfile, Limits, RunConfig},
+        Day, PuzzleId, Year,
+    };
+    use std::{path::PathBuf, process, time::Duration};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
             download: bool,
             overwrite: bool,
+            result: bool,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            input: Option<InputSource>,
+            example: Option<Example>,
+            watch: Option<WatchMode>,
         },
         All {
-            release: bool,
+            year: Option<Year>,
+            config: RunConfig,
+            report: Option<ReportConfig>,
         },
         Time {
+            year: Option<Year>,
             all: bool,
             day: Option<Day>,
             store: bool,
+            config: RunConfig,
+            compare: Option<CompareConfig>,
+            report: Option<ReportConfig>,
+        },
+        TimeHistory {
+            year: Option<Year>,
+            day: Day,
+        },
+        Verify {
+            year: Option<Year>,
+            config: RunConfig,
+            store: bool,
+        },
+        Readme {
+            year: Option<Year>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: Option<Year>,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // every subcommand accepts `--year <year>` to use the per-year layout.
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                year,
+                config: RunConfig {
+                    profile: BuildProfile::new(args.contains("--release"), false),
+                    subprocess: args.contains("--subprocess"),
+                    bench: None,
+                    example: parse_example(&mut args)?,
+                    limits: parse_limits(&mut args)?,
+                    jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                },
+                report: parse_report(&mut args)?,
+            },
+            Some("time") if args.contains("--history") => AppArguments::TimeHistory {
+                year,
+                day: args.free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let subprocess = args.contains("--subprocess");
+                // both declare a global allocator, so they can not be combined.
+                let profile = match (args.contains("--dhat"), args.contains("--alloc")) {
+                    (true, alloc) => {
+                        if alloc {
+                            eprintln!(
+                                "`--alloc` can not be combined with `--dhat`, using `--dhat`."
+                            );
+                        }
+                        BuildProfile::Dhat
+                    }
+                    (false, true) => BuildProfile::Alloc,
+                    (false, false) => BuildProfile::Release,
+                };
+
+                // counting allocations skews the timings, and the counts themselves are not stored.
+                if store && profile == BuildProfile::Alloc {
+                    return Err("`--store` can not be combined with `--alloc`.".into());
+                }
+
+                let defaults = BenchConfig::default();
+                let bench = BenchConfig {
+                    warmup: args
+                        .opt_value_from_str("--warmup")?
+                        .unwrap_or(defaults.warmup),
+                    target_time: args
+                        .opt_value_from_str("--target-time")?
+                        .map_or(defaults.target_time, Duration::from_millis),
+                    samples: args.opt_value_from_str("--samples")?,
+                };
+
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(5.0);
+
+                let example = parse_example(&mut args)?;
+                let limits = parse_limits(&mut args)?;
+                let report = parse_report(&mut args)?;
+
+                // parallel runs skew benchmarks, so they have to be requested explicitly.
+                let parallel_timing = args.contains("--parallel-timing");
+                let jobs = match args.opt_value_from_str("--jobs")?.unwrap_or(1) {
+                    jobs if jobs > 1 && !parallel_timing => {
+                        eprintln!(
+                            "Timed runs are sequential, append `--parallel-timing` to run {jobs} jobs anyway."
+                        );
+                        1
+                    }
+                    jobs => jobs,
+                };
 
                 AppArguments::Time {
+                    year,
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    config: RunConfig {
+                        profile,
+                        subprocess,
+                        bench: Some(bench),
+                        example,
+                        limits,
+                        jobs,
+                    },
+                    compare: compare.then_some(CompareConfig { threshold }),
+                    report,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                year,
+                config: RunConfig {
+                    profile: BuildProfile::new(args.contains("--release"), false),
+                    subprocess: args.contains("--subprocess"),
+                    jobs: 1,
+                    ..RunConfig::default()
+                },
+                store: args.contains("--store"),
+            },
+            Some("readme") => AppArguments::Readme { year },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: PuzzleId::new(year, args.free_from_str()?),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: PuzzleId::new(year, args.free_from_str()?),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: PuzzleId::new(year, args.free_from_str()?),
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                result: args.contains("--result"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let watch = args.contains("--watch");
+                let tests = args.contains("--tests");
+                let input = parse_input_source(&mut args)?;
+                let example = parse_example(&mut args)?;
+
+                if input.is_some() && example.is_some() {
+                    return Err(
+                        "`--input` and `--inputs` can not be combined with `--example`.".into(),
+                    );
+                }
+
+                AppArguments::Solve {
+                    puzzle: PuzzleId::new(year, args.free_from_str()?),
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    dhat: args.contains("--dhat"),
+                    input,
+                    example,
+                    watch: match (watch, tests) {
+                        (false, _) => None,
+                        (true, false) => Some(WatchMode::Solve),
+                        (true, true) => Some(WatchMode::Tests),
+                    },
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today { year },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -93,42 +245,137 @@ mod args {
 
         Ok(app_args)
     }
+
+    fn parse_input_source(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<InputSource>, pico_args::Error> {
+        let files: Vec<PathBuf> = args.values_from_str("--input")?;
+        let dir: Option<PathBuf> = args.opt_value_from_str("--inputs")?;
+
+        Ok(match dir {
+            Some(dir) => Some(InputSource::Dir(dir)),
+            None if !files.is_empty() => Some(InputSource::Files(files)),
+            None => None,
+        })
+    }
+
+    fn parse_report(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<ReportConfig>, pico_args::Error> {
+        let format: Option<ReportFormat> = args.opt_value_from_str("--format")?;
+        let output: Option<PathBuf> = args.opt_value_from_str("--output")?;
+
+        // without `--format`, the format follows the extension of the output file.
+        let format = format.or_else(|| {
+            output
+                .as_deref()
+                .map(|path| ReportFormat::from_path(path).unwrap_or(ReportFormat::Json))
+        });
+
+        Ok(format.map(|format| ReportConfig { format, output }))
+    }
+
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, pico_args::Error> {
+        let timeout: Option<f64> = args.opt_value_from_str("--timeout")?;
+        let max_memory: Option<u64> = args.opt_value_from_str("--max-memory")?;
+
+        Ok(Limits {
+            timeout: timeout.map(Duration::from_secs_f64),
+            max_memory: max_memory.map(|mebibytes| mebibytes * 1024 * 1024),
+        })
+    }
+
+    fn parse_example(args: &mut pico_args::Arguments) -> Result<Option<Example>, pico_args::Error> {
+        let part: Option<u8> = args.opt_value_from_str("--example-part")?;
+        let example = args.contains("--example");
+
+        Ok(match part {
+            Some(part) => Some(Example::Part(part)),
+            None => example.then_some(Example::Default),
+        })
+    }
 }
 
 fn main() {
+    #[cfg(all(
+        feature = "in-process",
+        not(any(test, feature = "dhat-heap", feature = "count-alloc"))
+    ))]
+    advent_of_code::template::registry::register(solutions::SOLUTIONS);
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All {
+                year,
+                config,
+                report,
+            } => all::handle(year, &config, report.as_ref()),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                config,
+                compare,
+                report,
+            } => time::handle(
+                year,
                 day,
+                all,
+                store,
+                &config,
+                compare.as_ref(),
+                report.as_ref(),
+            ),
+            AppArguments::TimeHistory { year, day } => time::history(year, day),
+            AppArguments::Verify {
+                year,
+                config,
+                store,
+            } => verify::handle(year, &config, store),
+            AppArguments::Readme { year } => readme::handle(year),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold {
+                puzzle,
                 download,
                 overwrite,
+                result,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite, result);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
+                release,
+                dhat,
+                submit,
+                input,
+                example,
+                watch,
+            } => solve::handle(
+                puzzle,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                input.as_ref(),
+                example,
+                watch,
+            ),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
+            AppArguments::Today { year } => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        let puzzle = PuzzleId::new(year, day);
+                        scaffold::handle(puzzle, false, false);
+                        download::handle(puzzle);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,547 @@
+/// Helpers to parse the puzzle input without a regex or a chain of `unwrap()`.
+///
+/// ```ignore
+/// let robots: Vec<(i32, i32, i32, i32)> = extract_lines(input)?; // p=0,4 v=3,-3
+/// let machines = paragraphs(input).map(extract::<[i64; 6]>).collect::<Result<Vec<_>, _>>()?;
+/// let total: u32 = integers::<u32>(input).sum();
+/// ```
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Where parsing failed, with the line and column of the offending text (both starting at 1).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A number that does not fit in the requested type.
+    Overflow {
+        number: String,
+        type_name: &'static str,
+    },
+    /// The input has fewer numbers than requested.
+    MissingNumber { expected: usize, found: usize },
+    /// The input has more numbers than requested.
+    TooManyNumbers { expected: usize },
+    /// The input does not continue as expected, see [`Parser`].
+    Expected { expected: String, found: String },
+}
+
+impl ParseError {
+    fn at(input: &str, offset: usize, kind: ParseErrorKind) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            kind,
+        }
+    }
+
+    /// The same error in a larger input, where the parsed text starts at line `line`.
+    pub fn starting_at_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Overflow { number, type_name } => {
+                write!(f, "{number} does not fit in {type_name}.")
+            }
+            ParseErrorKind::MissingNumber { expected, found } => {
+                write!(f, "expected {expected} numbers, found {found}.")
+            }
+            ParseErrorKind::TooManyNumbers { expected } => {
+                write!(f, "expected {expected} numbers, found more.")
+            }
+            ParseErrorKind::Expected { expected, found } => {
+                write!(f, "expected {expected}, found {found}.")
+            }
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/* -------------------------------------------------------------------------- */
+
+/// Integer types that can be extracted from text.
+pub trait Integer: Copy {
+    /// Whether a `-` before the digits makes the number negative. Unsigned types treat it as a separator.
+    const SIGNED: bool;
+
+    /// The number of a run of ASCII digits, `None` if it does not fit.
+    fn from_digits(negative: bool, digits: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal, $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = $signed;
+
+                fn from_digits(negative: bool, digits: &[u8]) -> Option<Self> {
+                    // accumulating negative numbers down from zero also parses the minimum of the type.
+                    digits.iter().try_fold(0 as $t, |n, d| {
+                        let d = <$t>::from(d - b'0');
+                        let n = n.checked_mul(10)?;
+                        if negative { n.checked_sub(d) } else { n.checked_add(d) }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(false, u8, u16, u32, u64, u128, usize);
+impl_integer!(true, i16, i32, i64, i128, isize);
+
+impl Integer for i8 {
+    const SIGNED: bool = true;
+
+    fn from_digits(negative: bool, digits: &[u8]) -> Option<Self> {
+        let n = i16::from_digits(negative, digits)?;
+        i8::try_from(n).ok()
+    }
+}
+
+/// A run of digits in the input, with the `-` before it, see [`FromIntegers`].
+#[derive(Clone, Copy, Debug)]
+pub struct Number<'a> {
+    offset: usize,
+    negative: bool,
+    digits: &'a str,
+}
+
+impl Number<'_> {
+    fn parse<T: Integer>(&self, input: &str) -> Result<T, ParseError> {
+        let negative = T::SIGNED && self.negative;
+
+        T::from_digits(negative, self.digits.as_bytes()).ok_or_else(|| {
+            ParseError::at(
+                input,
+                self.offset - usize::from(negative),
+                ParseErrorKind::Overflow {
+                    number: format!("{}{}", if negative { "-" } else { "" }, self.digits),
+                    type_name: std::any::type_name::<T>(),
+                },
+            )
+        })
+    }
+}
+
+/// Every run of digits in the input, see [`integers`] for the sign.
+fn numbers(input: &str) -> impl Iterator<Item = Number<'_>> {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        if i == bytes.len() {
+            return None;
+        }
+
+        let offset = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        let negative = offset > 0
+            && bytes[offset - 1] == b'-'
+            && (offset < 2 || !bytes[offset - 2].is_ascii_alphanumeric());
+
+        Some(Number {
+            offset,
+            negative,
+            digits: &input[offset..i],
+        })
+    })
+}
+
+/// Every integer in the input, ignoring the text around them.
+/// A `-` right before the digits is a sign, unless it follows a letter or a digit like in `a-1` or `1-3`.
+///
+/// # Panics
+///
+/// If a number does not fit in `T`, use [`try_integers`] to handle it.
+pub fn integers<T: Integer>(input: &str) -> impl Iterator<Item = T> + '_ {
+    try_integers(input).map(|n| n.unwrap_or_else(|e| panic!("{e}")))
+}
+
+/// Every integer in the input, failing for numbers that do not fit in `T`.
+pub fn try_integers<T: Integer>(input: &str) -> impl Iterator<Item = Result<T, ParseError>> + '_ {
+    numbers(input).map(|n| n.parse(input))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Types that are made of a fixed number of integers, e.g. `(usize, usize)` or `[i64; 6]`.
+pub trait FromIntegers: Sized {
+    /// The number of integers.
+    const ARITY: usize;
+
+    #[doc(hidden)]
+    fn from_numbers<'a>(
+        input: &str,
+        numbers: &mut impl Iterator<Item = Number<'a>>,
+    ) -> Result<Self, ParseError>;
+}
+
+fn next_number<'a, T: Integer>(
+    input: &str,
+    numbers: &mut impl Iterator<Item = Number<'a>>,
+    expected: usize,
+    found: usize,
+) -> Result<T, ParseError> {
+    numbers
+        .next()
+        .ok_or_else(|| {
+            ParseError::at(
+                input,
+                input.len(),
+                ParseErrorKind::MissingNumber { expected, found },
+            )
+        })?
+        .parse(input)
+}
+
+macro_rules! impl_from_integers {
+    ($arity:literal; $($t:ident $i:literal),*) => {
+        impl<$($t: Integer),*> FromIntegers for ($($t,)*) {
+            const ARITY: usize = $arity;
+
+            fn from_numbers<'a>(
+                input: &str,
+                numbers: &mut impl Iterator<Item = Number<'a>>,
+            ) -> Result<Self, ParseError> {
+                Ok(($(next_number::<$t>(input, numbers, $arity, $i)?,)*))
+            }
+        }
+    };
+}
+
+impl_from_integers!(1; A 0);
+impl_from_integers!(2; A 0, B 1);
+impl_from_integers!(3; A 0, B 1, C 2);
+impl_from_integers!(4; A 0, B 1, C 2, D 3);
+impl_from_integers!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_integers!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+impl<T: Integer + Default, const N: usize> FromIntegers for [T; N] {
+    const ARITY: usize = N;
+
+    fn from_numbers<'a>(
+        input: &str,
+        numbers: &mut impl Iterator<Item = Number<'a>>,
+    ) -> Result<Self, ParseError> {
+        let mut array = [T::default(); N];
+        for (i, n) in array.iter_mut().enumerate() {
+            *n = next_number(input, numbers, N, i)?;
+        }
+        Ok(array)
+    }
+}
+
+/// Exactly `T::ARITY` integers from the input, e.g. `let (x, y): (usize, usize) = extract("12,5")?;`.
+pub fn extract<T: FromIntegers>(input: &str) -> Result<T, ParseError> {
+    let mut numbers = numbers(input);
+    let value = T::from_numbers(input, &mut numbers)?;
+
+    match numbers.next() {
+        Some(extra) => Err(ParseError::at(
+            input,
+            extra.offset,
+            ParseErrorKind::TooManyNumbers { expected: T::ARITY },
+        )),
+        None => Ok(value),
+    }
+}
+
+/// [`extract`] from every line of the input that is not blank, errors have the line number in the input.
+pub fn extract_lines<T: FromIntegers>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(y, line)| extract(line).map_err(|e| e.starting_at_line(y + 1)))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The lines of the input without the line endings, ignoring blank lines at the end.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end().lines()
+}
+
+/// The sections of the input separated by blank lines, e.g. the rules and the updates of a puzzle.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input.trim_matches(['\r', '\n']);
+
+    std::iter::from_fn(move || {
+        // blank lines that hold whitespace separate paragraphs too, so they never start one.
+        while let Some((line, tail)) = rest.split_once('\n') {
+            if !line.trim().is_empty() {
+                break;
+            }
+            rest = tail;
+        }
+
+        if rest.trim().is_empty() {
+            return None;
+        }
+
+        let mut end = rest.len();
+        let mut next = rest.len();
+        let mut line_start = 0;
+
+        for line in rest.split_inclusive('\n') {
+            let line_end = line_start + line.len();
+            if line.trim().is_empty() {
+                end = line_start.saturating_sub(1);
+                next = line_end;
+                break;
+            }
+            line_start = line_end;
+        }
+
+        let paragraph = rest[..end].trim_end_matches('\r');
+        rest = rest[next..].trim_start_matches(['\r', '\n']);
+        Some(paragraph)
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A cursor over the input for formats that [`extract`] cannot handle, e.g. to check the text between numbers:
+///
+/// ```ignore
+/// let mut parser = Parser::new(input);
+/// parser.expect("Register A: ")?;
+/// let a: u64 = parser.integer()?;
+/// parser.skip_whitespace();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Parser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input, offset: 0 }
+    }
+
+    /// The input that was not parsed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.offset..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = match self.rest().lines().next() {
+            None => "the end of the input".to_string(),
+            Some(line) if line.chars().count() > 20 => {
+                format!("\"{}…\"", line.chars().take(20).collect::<String>())
+            }
+            Some(line) => format!("\"{line}\""),
+        };
+
+        ParseError::at(
+            self.input,
+            self.offset,
+            ParseErrorKind::Expected {
+                expected: expected.into(),
+                found,
+            },
+        )
+    }
+
+    /// Skip a literal text, failing if the input does not continue with it.
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(literal) {
+            self.offset += literal.len();
+            Ok(())
+        } else {
+            Err(self.error(format!("{literal:?}")))
+        }
+    }
+
+    /// Skip a literal text if the input continues with it.
+    pub fn accept(&mut self, literal: &str) -> bool {
+        self.expect(literal).is_ok()
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    /// An integer at the current position, with a `-` sign for signed types.
+    pub fn integer<T: Integer>(&mut self) -> Result<T, ParseError> {
+        let rest = self.rest().as_bytes();
+        let sign = usize::from(T::SIGNED && rest.first() == Some(&b'-'));
+        let length = rest[sign..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+
+        if length == 0 {
+            return Err(self.error("a number"));
+        }
+
+        let number = Number {
+            offset: self.offset + sign,
+            negative: sign == 1,
+            digits: &self.rest()[sign..sign + length],
+        };
+        let value = number.parse(self.input)?;
+        self.offset += sign + length;
+        Ok(value)
+    }
+
+    /// Integers separated by a literal text, e.g. `0,3,5,4`.
+    pub fn separated<T: Integer>(&mut self, separator: &str) -> Result<Vec<T>, ParseError> {
+        let mut values = vec![self.integer()?];
+        while self.accept(separator) {
+            values.push(self.integer()?);
+        }
+        Ok(values)
+    }
+
+    /// The text until a literal text, which is skipped too.
+    pub fn until(&mut self, literal: &str) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let end = rest
+            .find(literal)
+            .ok_or_else(|| self.error(format!("{literal:?}")))?;
+
+        self.offset += end + literal.len();
+        Ok(&rest[..end])
+    }
+
+    /// Fail if some input is left, ignoring whitespace.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("the end of the input"))
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        extract, extract_lines, integers, lines, paragraphs, try_integers, ParseErrorKind, Parser,
+    };
+
+    #[test]
+    fn extracts_integers() {
+        let line = "p=0,4 v=3,-3";
+        assert_eq!(integers::<i32>(line).collect::<Vec<_>>(), [0, 4, 3, -3]);
+        assert_eq!(integers::<u32>(line).collect::<Vec<_>>(), [0, 4, 3, 3]);
+        assert_eq!(integers::<i32>("1-3 a-2").collect::<Vec<_>>(), [1, 3, 2]);
+        assert_eq!(integers::<i8>("-128 127").collect::<Vec<_>>(), [-128, 127]);
+
+        let error = try_integers::<u8>("1\n 256").nth(1).unwrap().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: 256 does not fit in u8."
+        );
+    }
+
+    #[test]
+    fn extracts_tuples() {
+        assert_eq!(extract::<(usize, i64)>("12,-5"), Ok((12, -5)));
+        assert_eq!(
+            extract::<[i64; 6]>(
+                "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400"
+            ),
+            Ok([94, 34, 22, 67, 8400, 5400])
+        );
+
+        let error = extract::<(u32, u32)>("1,2,3").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::TooManyNumbers { expected: 2 });
+        assert_eq!(error.column, 5);
+
+        let error = extract_lines::<(u32, u32)>("1,2\n\n3").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::MissingNumber {
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!((error.line, error.column), (3, 2));
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        let input = "47|53\r\n97|13\r\n\r\n75,47\n\n\n61\n";
+        assert_eq!(
+            paragraphs(input).collect::<Vec<_>>(),
+            ["47|53\r\n97|13", "75,47", "61"]
+        );
+        assert_eq!(lines("a\nb\n\n").collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn skips_whitespace_only_lines_between_paragraphs() {
+        assert_eq!(paragraphs("  \nabc").collect::<Vec<_>>(), ["abc"]);
+        assert_eq!(
+            paragraphs("a\n \n\t\r\nb\n  ").collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        assert_eq!(paragraphs(" \n \n").count(), 0);
+    }
+
+    #[test]
+    fn parses_with_a_cursor() {
+        let input = "Register A: 729\n\nProgram: 0,1,5,4,3,0\n";
+        let mut parser = Parser::new(input);
+
+        assert_eq!(parser.expect("Register A: "), Ok(()));
+        assert_eq!(parser.integer::<u64>(), Ok(729));
+        parser.skip_whitespace();
+        assert_eq!(parser.until(": "), Ok("Program"));
+        assert_eq!(parser.separated::<u8>(","), Ok(vec![0, 1, 5, 4, 3, 0]));
+        assert_eq!(parser.end(), Ok(()));
+
+        let error = Parser::new(input).expect("Register B: ").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected \"Register B: \", found \"Register A: 729\"."
+        );
+    }
+}
@@ -0,0 +1,438 @@
+/// Graph searches over any state type, e.g. a position or a position and a direction.
+///
+/// The graph is given by a closure returning the neighbours of a state, so it does not have to be built up-front:
+///
+/// ```ignore
+/// let search = bfs(start, |p| grid.neighbours4(*p).filter(|n| grid[*n] != '#'), |p| *p == end);
+/// let steps = search.goal_distance();
+/// let path = search.path_to(&end);
+/// ```
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The result of a search: the distance of every reached state, and the predecessor it was reached from.
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Self {
+            distances: HashMap::from([(start, zero)]),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The goal the search stopped at, `None` if no goal was reached.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// Distance of the goal the search stopped at.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// Distance of a state from the start. States that were not reached have no distance.
+    /// When the search stopped at a goal, the distances of states that were not expanded yet might not be final.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> HashMap<S, C> {
+        self.distances
+    }
+
+    /// A shortest path from the start to a state, including both.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(path.last()?) {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from a start state until a goal is reached. Every step has a distance of `1`.
+/// Pass `|_| false` as `is_goal` to reach every state, e.g. for a map of distances.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let distance = search.distances[&state] + 1;
+
+        for next in neighbours(&state) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's search from a start state until a goal is reached.
+/// `neighbours` returns every neighbour with the cost of the step to it. Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search from a start state until a goal is reached, expanding states in order of their distance plus `heuristic`.
+/// The heuristic estimates the remaining cost to a goal. It must never overestimate it and must not decrease by more
+/// than the cost of a step, e.g. the Manhattan distance on a grid, otherwise the found path might not be the shortest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let zero = C::default();
+    let mut search = Search::new(start.clone(), zero);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: zero,
+        state: start,
+    }]);
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        // a cheaper way to this state was found after this entry was pushed.
+        if search.distances.get(&state).is_some_and(|d| cost > *d) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if search.distances.get(&next).is_none_or(|d| next_cost < *d) {
+                search.distances.insert(next.clone(), next_cost);
+                search.predecessors.insert(next.clone(), state.clone());
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The result of [`dijkstra_all`]: every predecessor a state can be reached from at its shortest distance.
+#[derive(Clone, Debug)]
+pub struct AllPaths<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> AllPaths<S, C> {
+    /// Every goal reached at the shortest distance, e.g. the same tile facing different directions.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The shortest distance of the goals.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// The predecessors of a state on its shortest paths.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every state on any shortest path from the start to one of the goals, including both.
+    pub fn states_on_paths(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack: Vec<S> = self.goals.clone();
+
+        while let Some(state) = stack.pop() {
+            for previous in self.predecessors(&state) {
+                if states.insert(previous.clone()) {
+                    stack.push(previous.clone());
+                }
+            }
+        }
+
+        states
+    }
+
+    /// Every shortest path from the start to a state, including both.
+    /// The number of paths can grow exponentially, prefer [`AllPaths::states_on_paths`] when the paths are not needed.
+    pub fn paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return vec![];
+        }
+
+        let mut paths = vec![];
+        let mut stack = vec![vec![state.clone()]];
+
+        while let Some(path) = stack.pop() {
+            let predecessors = path.last().map_or(&[][..], |s| self.predecessors(s));
+
+            if predecessors.is_empty() {
+                let mut path = path;
+                path.reverse();
+                paths.push(path);
+                continue;
+            }
+
+            for previous in predecessors {
+                let mut longer = path.clone();
+                longer.push(previous.clone());
+                stack.push(longer);
+            }
+        }
+
+        paths
+    }
+}
+
+/// Dijkstra's search that keeps every predecessor a state is reached from at its shortest distance, to enumerate
+/// every shortest path. It stops once every goal at the shortest distance was reached. Costs must be positive.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> AllPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let zero = C::default();
+    let mut result = AllPaths {
+        distances: HashMap::from([(start.clone(), zero)]),
+        predecessors: HashMap::new(),
+        goals: vec![],
+    };
+    let mut heap = BinaryHeap::from([Entry {
+        priority: zero,
+        cost: zero,
+        state: start,
+    }]);
+    let mut goal_cost: Option<C> = None;
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+
+        if result.distances.get(&state).is_some_and(|d| cost > *d) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            result.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            match result.distances.get(&next).map(|d| next_cost.cmp(d)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => {
+                    result
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(state.clone());
+                }
+                Some(Ordering::Less) | None => {
+                    result.distances.insert(next.clone(), next_cost);
+                    result
+                        .predecessors
+                        .insert(next.clone(), vec![state.clone()]);
+                    heap.push(Entry {
+                        priority: next_cost,
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An entry of the priority queue, ordered so that the lowest priority is popped first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra, dijkstra_all};
+    use crate::geometry::Direction;
+    use crate::grid::{Grid, Position};
+
+    const MAZE: &str = "\
+#######
+#S..#.#
+#.#...#
+#...#E#
+#######";
+
+    fn maze() -> (Grid<char>, Position, Position) {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+        (grid, start, end)
+    }
+
+    fn open(grid: &Grid<char>, position: Position) -> impl Iterator<Item = Position> + '_ {
+        grid.neighbours4(position).filter(|p| grid[*p] != '#')
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let (grid, start, end) = maze();
+        let search = bfs(start, |p| open(&grid, *p), |p| *p == end);
+
+        assert_eq!(search.goal(), Some(&end));
+        assert_eq!(search.goal_distance(), Some(6));
+
+        let path = search.path_to(&end).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&start));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+    }
+
+    #[test]
+    fn maps_distances() {
+        let (grid, start, end) = maze();
+        let search = bfs(end, |p| open(&grid, *p), |_| false);
+
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.distance(&start), Some(6));
+        assert_eq!(search.distances().len(), 12);
+        assert_eq!(search.distance(&Position::new(0, 0)), None);
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        let (grid, start, end) = maze();
+        let grid = &grid;
+        // moving down is expensive, so the path through the top row is cheaper.
+        let weighted = |p: &Position| {
+            let p = *p;
+            Direction::ALL.into_iter().filter_map(move |d| {
+                let cost = if d == Direction::Down { 5 } else { 1 };
+                grid.step(p, d)
+                    .filter(|n| grid[*n] != '#')
+                    .map(|n| (n, cost))
+            })
+        };
+
+        let search = dijkstra(start, weighted, |p| *p == end);
+        assert_eq!(search.goal_distance(), Some(14));
+        assert!(search.path_to(&end).unwrap().contains(&Position::new(3, 1)));
+
+        let estimated = astar(start, weighted, |p| p.manhattan(end), |p| *p == end);
+        assert_eq!(estimated.goal_distance(), Some(14));
+    }
+
+    #[test]
+    fn finds_all_shortest_paths() {
+        let grid: Grid<char> = "S..\n...\n..E".parse().unwrap();
+        let start = Position::new(0, 0);
+        let end = Position::new(2, 2);
+
+        let paths = dijkstra_all(start, |p| open(&grid, *p).map(|n| (n, 1)), |p| *p == end);
+        assert_eq!(paths.goals(), &[end]);
+        assert_eq!(paths.goal_distance(), Some(4));
+        assert_eq!(paths.paths_to(&end).len(), 6);
+        assert_eq!(paths.states_on_paths().len(), 9);
+    }
+}
@@ -0,0 +1,122 @@
+/// A global allocator that counts allocations, used by `cargo time --alloc` to report allocations per iteration.
+///
+/// Solutions declare it as their global allocator when built with the `count-alloc` feature, see `solution!`.
+/// Unlike dhat, it only keeps two counters, so benchmarks stay representative.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::template::memory::format_bytes;
+
+/// Whether solutions count their allocations, i.e. were built with `count-alloc` and without `dhat-heap`.
+pub const ENABLED: bool = cfg!(all(feature = "count-alloc", not(feature = "dhat-heap")));
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Counts every allocation and the allocated bytes, then delegates to the system allocator.
+/// A reallocation counts as an allocation of its new size.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+fn count(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+}
+
+/// Allocations counted since the last [`reset`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocCount {
+    pub allocations: u64,
+    pub bytes: u64,
+}
+
+impl AllocCount {
+    /// Read the counters.
+    pub fn get() -> Self {
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Average allocations of a number of iterations.
+    pub fn per_iteration(&self, iterations: u128) -> AllocsPerIteration {
+        #[allow(clippy::cast_precision_loss)]
+        let iterations = iterations.max(1) as f64;
+
+        #[allow(clippy::cast_precision_loss)]
+        AllocsPerIteration {
+            allocations: self.allocations as f64 / iterations,
+            bytes: self.bytes as f64 / iterations,
+        }
+    }
+}
+
+/// Reset the counters, e.g. before a benchmark loop.
+pub fn reset() {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+}
+
+/// Average allocations of a benchmark iteration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AllocsPerIteration {
+    pub allocations: f64,
+    pub bytes: f64,
+}
+
+impl Display for AllocsPerIteration {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.1} allocations · {} per iteration",
+            self.allocations,
+            format_bytes(self.bytes.round() as u64)
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::AllocCount;
+
+    #[test]
+    fn averages_iterations() {
+        let count = AllocCount {
+            allocations: 30,
+            bytes: 3 * 4096,
+        };
+
+        assert_eq!(
+            count.per_iteration(3).to_string(),
+            "10.0 allocations · 4.0 KiB per iteration"
+        );
+        assert_eq!(count.per_iteration(0).allocations, 30.0);
+    }
+}
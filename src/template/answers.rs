@@ -0,0 +1,195 @@
+/// Module that records accepted answers in `data/answers/NN.txt` and verifies solutions against them.
+use std::{fmt::Display, fs, io};
+
+use crate::template::{Day, PuzzleId, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// Represents the recorded answers of a single day.
+/// Stored as one `Part N: <answer>` line per part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    /// Read the answers for a day. If not present, returns no answers.
+    pub fn read_from_file(puzzle: PuzzleId) -> Self {
+        fs::read_to_string(puzzle.data_path("answers"))
+            .map(|contents| Self::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    /// Write the answers for a day, creating `data/answers` if needed.
+    pub fn store_file(&self, puzzle: PuzzleId) -> Result<(), io::Error> {
+        let path = puzzle.data_path("answers");
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    /// Record the answer to a part, overwriting a previous answer.
+    pub fn record(puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), io::Error> {
+        let mut answers = Self::read_from_file(puzzle);
+        *answers.part_mut(part) = Some(answer.to_string());
+        answers.store_file(puzzle)
+    }
+
+    /// The recorded answer to a part.
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Mutable access to the recorded answer of a part. Any part other than `1` refers to part 2.
+    pub fn part_mut(&mut self, part: u8) -> &mut Option<String> {
+        if part == 1 {
+            &mut self.part_1
+        } else {
+            &mut self.part_2
+        }
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut answers = Self::default();
+
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+
+            match key.trim() {
+                "Part 1" => answers.part_1 = Some(value.trim().to_string()),
+                "Part 2" => answers.part_2 = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+
+        answers
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(answer) = &self.part_1 {
+            writeln!(f, "Part 1: {answer}")?;
+        }
+        if let Some(answer) = &self.part_2 {
+            writeln!(f, "Part 2: {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The result of checking a part's answer against the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No answer has been recorded for this part yet.
+    Missing {
+        actual: Option<String>,
+    },
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match (expected, actual) {
+            (None, actual) => Verdict::Missing {
+                actual: actual.map(Into::into),
+            },
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Match,
+            (Some(expected), actual) => Verdict::Mismatch {
+                expected: expected.into(),
+                actual: actual.map(Into::into),
+            },
+        }
+    }
+}
+
+/// Print a verification table with a line per part.
+pub fn print_table(verdicts: &[(Day, u8, Verdict)]) {
+    println!(
+        "{ANSI_BOLD}{:<5} {:<6} {:<10} {:<20} {:<20}{ANSI_RESET}",
+        "Day", "Part", "Status", "Expected", "Actual"
+    );
+
+    for (day, part, verdict) in verdicts {
+        let (color, status, expected, actual) = match verdict {
+            Verdict::Match => (ANSI_GREEN, "match", "", ""),
+            Verdict::Mismatch { expected, actual } => (
+                ANSI_RED,
+                "mismatch",
+                expected.as_str(),
+                actual.as_deref().unwrap_or("✖"),
+            ),
+            Verdict::Missing { actual } => ("", "missing", "-", actual.as_deref().unwrap_or("✖")),
+        };
+
+        println!(
+            "{color}{:<5} {:<6} {:<10} {:<20} {:<20}{ANSI_RESET}",
+            day.to_string(),
+            part,
+            status,
+            expected,
+            actual
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = Answers {
+            part_1: Some("1234".into()),
+            part_2: Some("6,1".into()),
+        };
+        assert_eq!(answers.to_string(), "Part 1: 1234\nPart 2: 6,1\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = Answers::parse("Part 2: 42\n\nnot an answer\n");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("42"));
+    }
+
+    #[test]
+    fn compares_answers() {
+        assert_eq!(Verdict::new(Some("1"), Some("1")), Verdict::Match);
+        assert_eq!(
+            Verdict::new(Some("1"), Some("2")),
+            Verdict::Mismatch {
+                expected: "1".into(),
+                actual: Some("2".into())
+            }
+        );
+        assert_eq!(
+            Verdict::new(Some("1"), None),
+            Verdict::Mismatch {
+                expected: "1".into(),
+                actual: None
+            }
+        );
+        assert_eq!(
+            Verdict::new(None, Some("2")),
+            Verdict::Missing {
+                actual: Some("2".into())
+            }
+        );
+    }
+}
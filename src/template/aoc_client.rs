@@ -0,0 +1,390 @@
+/// Built-in client for the Advent of Code website.
+///
+/// The session cookie is read from the `AOC_SESSION` env var or the `~/.adventofcode.session` file.
+/// The base URL defaults to `https://adventofcode.com` and can be overridden with `AOC_BASE_URL`.
+/// Puzzles without a year use the year set by `AOC_YEAR`.
+use std::{env, fmt::Display, fs, io, path::PathBuf};
+
+use crate::template::{markdown, Day, Year};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static SESSION_FILE_NAME: &str = ".adventofcode.session";
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    YearNotSet,
+    Request(Box<ureq::Error>),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set the `AOC_SESSION` env var or create the file `~/{SESSION_FILE_NAME}`."
+            ),
+            AocClientError::YearNotSet => {
+                write!(f, "no year given and the `AOC_YEAR` env var is not set.")
+            }
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Request(Box::new(e))
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    Incorrect {
+        hint: Option<AnswerHint>,
+        /// The penalty before the next answer may be submitted, which grows with repeated wrong answers.
+        wait_seconds: u64,
+    },
+    /// An answer was submitted too recently.
+    Wait {
+        seconds: u64,
+    },
+    /// The part has already been solved, or the previous part has not.
+    WrongLevel,
+    Unknown,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnswerHint {
+    TooHigh,
+    TooLow,
+}
+
+impl SubmissionOutcome {
+    /// Classify the text of the response to a submission.
+    pub fn from_response(text: &str) -> Self {
+        if text.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(AnswerHint::TooHigh)
+            } else if text.contains("too low") {
+                Some(AnswerHint::TooLow)
+            } else {
+                None
+            };
+            SubmissionOutcome::Incorrect {
+                hint,
+                wait_seconds: parse_penalty_seconds(text).unwrap_or(0),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            SubmissionOutcome::Wait {
+                seconds: parse_wait_seconds(text).unwrap_or(0),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmissionOutcome::WrongLevel
+        } else {
+            SubmissionOutcome::Unknown
+        }
+    }
+
+    /// Seconds to wait before the next answer may be submitted, if the outcome imposes a cooldown.
+    pub fn cooldown(&self) -> Option<u64> {
+        match self {
+            SubmissionOutcome::Wait { seconds }
+            | SubmissionOutcome::Incorrect {
+                wait_seconds: seconds,
+                ..
+            } => Some(*seconds).filter(|s| *s > 0),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the remaining wait time from a text like `You have 1m 30s left to wait.`
+fn parse_wait_seconds(text: &str) -> Option<u64> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let duration = before.rsplit("You have ").next()?;
+
+    duration
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+/// Parse the penalty of a wrong answer from a text like `please wait 5 minutes before trying again.`
+fn parse_penalty_seconds(text: &str) -> Option<u64> {
+    let text = text.to_lowercase();
+    let (_, after) = text.split_once("please wait ")?;
+    let mut words = after.split_whitespace();
+
+    let value = match words.next()? {
+        "a" | "an" | "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        value => value.parse().ok()?,
+    };
+
+    match words
+        .next()?
+        .trim_end_matches(['.', ','])
+        .trim_end_matches('s')
+    {
+        "second" => Some(value),
+        "minute" => Some(value * 60),
+        "hour" => Some(value * 3600),
+        _ => None,
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: Year) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Create a client for a year from the environment, see the module documentation.
+    pub fn from_env(year: Option<Year>) -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let year = year
+            .or_else(Year::from_env)
+            .ok_or(AocClientError::YearNotSet)?;
+        let session = read_session().ok_or(AocClientError::SessionNotFound)?;
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Fetch the puzzle input of a day.
+    pub fn fetch_input(&self, day: Day) -> Result<String, AocClientError> {
+        let url = format!("{}/input", self.day_url(day));
+        Ok(self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?)
+    }
+
+    /// Fetch the puzzle description of a day, converted to Markdown.
+    pub fn fetch_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self
+            .agent
+            .get(&self.day_url(day))
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?;
+
+        Ok(markdown::extract_articles(&html)
+            .into_iter()
+            .map(markdown::html_to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Submit an answer. Returns the verdict and the response message as Markdown.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<(SubmissionOutcome, String), AocClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        let message = markdown::extract_articles(&html)
+            .into_iter()
+            .map(markdown::html_to_markdown)
+            .collect::<String>();
+
+        Ok((SubmissionOutcome::from_response(&message), message))
+    }
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    fs::read_to_string(PathBuf::from(home).join(SESSION_FILE_NAME))
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{
+        parse_penalty_seconds, parse_wait_seconds, AnswerHint, AocClient, SubmissionOutcome,
+    };
+    use crate::{day, year};
+
+    /// Serve a single canned response and send the received request back through the channel.
+    fn serve_once(body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            tx.send(request).unwrap();
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (base_url, rx) = serve_once("1 2\n3 4\n");
+        let client = AocClient::new(&base_url, "abc\n", year!(2024));
+        assert_eq!(client.fetch_input(day!(1)).unwrap(), "1 2\n3 4\n");
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn fetches_puzzle() {
+        let (base_url, _rx) = serve_once(
+            r#"<html><main><article class="day-desc"><h2>--- Day 5 ---</h2><p>Hi</p></article></main></html>"#,
+        );
+        let client = AocClient::new(&base_url, "abc", year!(2024));
+        assert_eq!(
+            client.fetch_puzzle(day!(5)).unwrap(),
+            "## --- Day 5 ---\n\nHi\n"
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, rx) = serve_once(
+            "<main><article><p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "abc", year!(2024));
+        let (outcome, message) = client.submit(day!(12), 2, "42").unwrap();
+        assert_eq!(
+            outcome,
+            SubmissionOutcome::Incorrect {
+                hint: Some(AnswerHint::TooHigh),
+                wait_seconds: 60,
+            }
+        );
+        assert!(message.starts_with("That's not the right answer"));
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/12/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn classifies_responses() {
+        assert_eq!(
+            SubmissionOutcome::from_response(
+                "That's the right answer! You are one gold star closer."
+            ),
+            SubmissionOutcome::Correct
+        );
+        assert_eq!(
+            SubmissionOutcome::from_response("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 5s left to wait."),
+            SubmissionOutcome::Wait { seconds: 65 }
+        );
+        assert_eq!(
+            SubmissionOutcome::from_response(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            SubmissionOutcome::WrongLevel
+        );
+        assert_eq!(parse_wait_seconds("You have 30s left to wait."), Some(30));
+        assert_eq!(
+            SubmissionOutcome::from_response("That's not the right answer. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+            SubmissionOutcome::Incorrect {
+                hint: None,
+                wait_seconds: 300
+            }
+        );
+        assert_eq!(
+            parse_penalty_seconds("Please wait 30 seconds before trying again."),
+            Some(30)
+        );
+    }
+}
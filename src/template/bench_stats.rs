@@ -0,0 +1,183 @@
+/// Summary statistics over the samples collected while benchmarking a solution part.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Statistics of a benchmark run.
+/// Outliers are rejected with Tukey's fences (1.5 × IQR) before computing the remaining values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    pub samples: u128,
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics for a set of samples, returns [`None`] if there are no samples.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 0.25).as_nanos() as f64;
+        let q3 = percentile(&sorted, 0.75).as_nanos() as f64;
+        let iqr = q3 - q1;
+        let (lower, upper) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let kept: Vec<Duration> = sorted
+            .iter()
+            .filter(|d| (lower..=upper).contains(&(d.as_nanos() as f64)))
+            .copied()
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / n;
+        let variance = kept
+            .iter()
+            .map(|d| (d.as_nanos() as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Some(Self {
+            mean: Duration::from_nanos(mean as u64),
+            min: kept[0],
+            median: median(&kept),
+            p95: percentile(&kept, 0.95),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+            samples: sorted.len() as u128,
+            outliers: (sorted.len() - kept.len()) as u128,
+        })
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (key, duration) in [
+            ("mean_nanos", value.mean),
+            ("min_nanos", value.min),
+            ("median_nanos", value.median),
+            ("p95_nanos", value.p95),
+            ("stddev_nanos", value.stddev),
+        ] {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        Ok(BenchStats {
+            mean: duration("mean_nanos")?,
+            min: duration("min_nanos")?,
+            median: duration("median_nanos")?,
+            p95: duration("p95_nanos")?,
+            stddev: duration("stddev_nanos")?,
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::BenchStats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[40, 10, 30, 20])).unwrap();
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+        assert_eq!(stats.stddev, Duration::from_nanos(11));
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 11, 1000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.p95, Duration::from_nanos(12));
+        assert_eq!(stats.mean, Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = BenchStats::from_samples(&nanos(&[5, 7, 9])).unwrap();
+        let parsed = BenchStats::try_from(&JsonValue::from(&stats)).unwrap();
+        assert_eq!(parsed, stats);
+    }
+}
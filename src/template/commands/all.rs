@@ -1,5 +1,17 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    all_days,
+    report::{Report, ReportConfig},
+    run_multi::run_multi,
+    runner::RunConfig,
+    Year,
+};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(year: Option<Year>, config: &RunConfig, report: Option<&ReportConfig>) {
+    let run = run_multi(year, &all_days().collect(), config);
+
+    if let Some(report) = report {
+        if let Err(e) = report.write(&Report::new(&run)) {
+            eprintln!("Failed to write report: {e}");
+        }
+    }
 }
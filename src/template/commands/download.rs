@@ -1,14 +1,43 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::{aoc_client, PuzzleId};
+use std::{fs, path::Path, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+pub fn handle(puzzle: PuzzleId) {
+    let client = match aoc_client::AocClient::from_env(puzzle.year) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to set up the Advent of Code client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let input_path = puzzle.data_path("inputs");
+    let puzzle_path = puzzle.data_path("puzzles").with_extension("md");
+
+    let result = client
+        .fetch_input(puzzle.day)
+        .and_then(|input| Ok(write_file(&input_path, &input)?))
+        .and_then(|()| client.fetch_puzzle(puzzle.day))
+        .and_then(|description| Ok(write_file(&puzzle_path, &description)?));
+
+    if let Err(e) = result {
+        eprintln!("failed to download day {puzzle}: {e}");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+}
+
+/// Write a file, creating its folder if needed (e.g. `data/2023/inputs`).
+pub fn write_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
 }
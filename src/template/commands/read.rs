@@ -1,15 +1,23 @@
 use std::process;
 
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_client, commands::download::write_file, PuzzleId};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: PuzzleId) {
+    let description = aoc_client::AocClient::from_env(puzzle.year)
+        .and_then(|client| client.fetch_puzzle(puzzle.day));
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    match description {
+        Ok(description) => {
+            // keep the local copy of the puzzle up to date, e.g. after part one was solved.
+            let _ = write_file(
+                &puzzle.data_path("puzzles").with_extension("md"),
+                &description,
+            );
+            println!("{description}");
+        }
+        Err(e) => {
+            eprintln!("failed to read day {puzzle}: {e}");
+            process::exit(1);
+        }
+    }
 }
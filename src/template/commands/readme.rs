@@ -0,0 +1,16 @@
+use std::process;
+
+use crate::template::{readme, Year};
+
+pub fn handle(year: Option<Year>) {
+    match readme::update(year) {
+        Ok(path) => println!("Updated {}.", path.display()),
+        Err(e) => {
+            eprintln!(
+                "Failed to update {}: {e}",
+                readme::readme_path(year).display()
+            );
+            process::exit(1);
+        }
+    }
+}
@@ -1,14 +1,27 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::PuzzleId;
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+/// The module template of solutions whose parts return a `Result`, used with `--result`.
+const RESULT_MODULE_TEMPLATE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/src/template_result.txt"
+));
+
+const EXAMPLE_SIDECAR: &str = "\
+# Expected answers of the example, checked by `cargo test` once uncommented.
+# expected_part_one =
+# expected_part_two =
+";
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -19,7 +32,7 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +40,22 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: PuzzleId, overwrite: bool, result: bool) {
+    let input_path = puzzle.data_path("inputs");
+    let example_path = puzzle.data_path("examples");
+    let module_path = puzzle.bin_path();
+
+    for path in [&input_path, &example_path] {
+        if let Err(e) = path.parent().map_or(Ok(()), fs::create_dir_all) {
+            eprintln!("Failed to create data folder: {e}");
+            process::exit(1);
+        }
+    }
+
+    let solution_args = match puzzle.year {
+        Some(year) => format!("{}, year = {year}", puzzle.day.into_inner()),
+        None => puzzle.day.into_inner().to_string(),
+    };
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,9 +65,15 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
+    let template = if result {
+        RESULT_MODULE_TEMPLATE
+    } else {
         MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+    };
+
+    match file.write_all(
+        template
+            .replace("%SOLUTION_ARGS%", &solution_args)
             .as_bytes(),
     ) {
         Ok(()) => {
@@ -56,7 +87,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -66,7 +97,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -74,6 +105,24 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    let sidecar_path = example_path.with_extension("toml");
+    match create_file(&sidecar_path).and_then(|mut file| file.write_all(EXAMPLE_SIDECAR.as_bytes()))
+    {
+        Ok(()) => {
+            println!("Created example sidecar \"{}\"", sidecar_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to create example sidecar: {e}");
+            process::exit(1);
+        }
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    match puzzle.year {
+        Some(year) => println!(
+            "🎄 Type `cargo solve {} --year {year}` to run your solution.",
+            puzzle.day
+        ),
+        None => println!("🎄 Type `cargo solve {}` to run your solution.", puzzle.day),
+    }
 }
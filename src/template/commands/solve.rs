@@ -1,20 +1,96 @@
-use std::process::{Command, Stdio};
+use std::{
+    env, fs,
+    process::{self, Command, Stdio},
+    thread,
+    time::Duration,
+};
 
-use crate::template::Day;
+use crate::template::{
+    inputs::{Example, InputSource},
+    part_result::{PartResult, RESULTS_FILE_ENV},
+    runner::BuildProfile,
+    watch::{self, Snapshot},
+    PuzzleId, ANSI_CLEAR, ANSI_ITALIC, ANSI_RESET,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// How often watched files are checked for modifications.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
 
-    if dhat {
-        cmd_args.extend([
-            "--profile".to_string(),
-            "dhat".to_string(),
-            "--features".to_string(),
-            "dhat-heap".to_string(),
-        ]);
-    } else if release {
-        cmd_args.push("--release".to_string());
+/// What to re-run when a watched file changes, passed as `--watch` and optionally `--tests`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchMode {
+    /// Run the solution, against its input or the inputs passed with `--input`/`--inputs`.
+    Solve,
+    /// Run the tests of the solution.
+    Tests,
+}
+
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    input: Option<&InputSource>,
+    example: Option<Example>,
+    watch: Option<WatchMode>,
+) {
+    let Some(mode) = watch else {
+        let mut cmd = solve_command(puzzle, release, dhat, submit_part, input, example)
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .unwrap();
+
+        cmd.wait().unwrap();
+        return;
+    };
+
+    if submit_part.is_some() {
+        eprintln!("Answers are not submitted in watch mode.");
+    }
+
+    let mut previous: Vec<PartResult> = vec![];
+    let mut snapshot: Option<Snapshot> = None;
+
+    loop {
+        let current = Snapshot::take(&watch::watched_paths(puzzle, input));
+
+        if snapshot.as_ref() != Some(&current) {
+            snapshot = Some(current);
+
+            print!("{ANSI_CLEAR}");
+            println!(
+                "{ANSI_ITALIC}Watching {puzzle} for changes, press Ctrl+C to stop.{ANSI_RESET}\n"
+            );
+
+            match mode {
+                WatchMode::Solve => {
+                    if let Some(results) = run_solve(puzzle, release, dhat, input, example) {
+                        println!();
+                        for line in watch::format_changes(&previous, &results) {
+                            println!("{line}");
+                        }
+                        previous = results;
+                    }
+                }
+                WatchMode::Tests => run_tests(puzzle, release),
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
     }
+}
+
+fn solve_command(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    input: Option<&InputSource>,
+    example: Option<Example>,
+) -> Command {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
+    cmd_args.extend(BuildProfile::new(release, dhat).cargo_args());
 
     cmd_args.push("--".to_string());
 
@@ -23,12 +99,59 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    if let Some(input) = input {
+        cmd_args.extend(input.to_args());
+    }
+
+    if let Some(example) = example {
+        cmd_args.extend(example.to_args());
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(&cmd_args);
+    cmd
+}
+
+/// Build and run the solution, returning its results. Returns `None` if the build or the run failed.
+fn run_solve(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    input: Option<&InputSource>,
+    example: Option<Example>,
+) -> Option<Vec<PartResult>> {
+    let results_path = env::temp_dir().join(format!("aoc-watch-{}-{puzzle}.jsonl", process::id()));
+    let _ = fs::remove_file(&results_path);
+
+    let status = solve_command(puzzle, release, dhat, None, input, example)
+        .env(RESULTS_FILE_ENV, &results_path)
+        .status();
+
+    let results = PartResult::read_all(&results_path);
+    let _ = fs::remove_file(&results_path);
+
+    match (status, results) {
+        (Ok(status), Ok(results)) if status.success() => Some(results),
+        (Ok(_), _) => {
+            eprintln!("\nThe solution did not run successfully, waiting for changes.");
+            None
+        }
+        (Err(e), _) => {
+            eprintln!("\nFailed to run cargo: {e}");
+            None
+        }
+    }
+}
+
+fn run_tests(puzzle: PuzzleId, release: bool) {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["test", "--bin", &puzzle.bin_name()]);
 
-    cmd.wait().unwrap();
+    if release {
+        cmd.arg("--release");
+    }
+
+    if let Err(e) = cmd.status() {
+        eprintln!("Failed to run cargo: {e}");
+    }
 }
@@ -1,16 +1,57 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::compare;
+use crate::template::report::{Report, ReportConfig};
 use crate::template::run_multi::run_multi;
+use crate::template::runner::{BuildProfile, RunConfig};
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::timings_history::{self, HistoryEntry};
+use crate::template::{all_days, readme, Day, Year};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Compare a run against the stored timings, failing if a part regressed by more than `threshold` percent.
+pub struct CompareConfig {
+    pub threshold: f64,
+}
+
+/// Bench a set of days, `config.bench` holds the benchmark settings.
+pub fn handle(
+    year: Option<Year>,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    config: &RunConfig,
+    compare: Option<&CompareConfig>,
+    report: Option<&ReportConfig>,
+) {
+    let (profile, example) = (config.profile, config.example);
+
+    // timings of examples are not comparable to the stored timings of puzzle inputs.
+    let (store, compare) = match example {
+        Some(_) if store || compare.is_some() => {
+            eprintln!("Timings of examples are not stored or compared.");
+            (false, None)
+        }
+        _ => (store, compare),
+    };
+
+    if profile == BuildProfile::Dhat {
+        if store {
+            eprintln!("Timings measured with --dhat include the overhead of the profiler, only the heap usage is stored.");
+        } else {
+            eprintln!("Timings measured with --dhat include the overhead of the profiler.");
+        }
+    }
+
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
+            if run_all || example.is_some() {
                 all_days().collect()
+            } else if compare.is_some() {
+                // when comparing, re-run every day that has a baseline.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
@@ -21,20 +62,75 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let run = run_multi(year, &days_to_run, config);
+
+    if let Some(report) = report {
+        if let Err(e) = report.write(&Report::new(&run)) {
+            eprintln!("Failed to write report: {e}");
+        }
+    }
+
+    let timings = run.timings.unwrap_or_default();
+
+    let mut has_regressions = false;
+
+    if let Some(config) = compare {
+        let deltas = compare::compare(&stored_timings, &timings);
+        println!();
+        compare::print_table(&deltas, config.threshold);
+
+        let regressions = deltas
+            .iter()
+            .filter(|d| d.is_regression(config.threshold))
+            .count();
+
+        if regressions > 0 {
+            eprintln!(
+                "\n{regressions} part(s) regressed by more than {}% or have no time.",
+                config.threshold
+            );
+            has_regressions = true;
+        }
+    }
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        if profile == BuildProfile::Dhat {
+            // the history only tracks times, which are skewed by the profiler.
+            stored_timings
+                .merge_memory(&timings)
+                .store_file(year)
+                .unwrap();
+        } else {
+            stored_timings.merge(&timings).store_file(year).unwrap();
+
+            if let Err(e) = HistoryEntry::new(profile.name(), timings).append_to_file(year) {
+                eprintln!("Failed to append to timing history: {e}");
+            }
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
+        match readme::update(year) {
+            Ok(path) => {
+                println!("Stored updated benchmarks in {}.", path.display());
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Print how the stored timings of a day evolved over time.
+pub fn history(year: Option<Year>, day: Day) {
+    match HistoryEntry::read_all_from_file(year) {
+        Ok(entries) => timings_history::print_trend(&entries, day),
+        Err(e) => {
+            eprintln!("Failed to read timing history: {e}");
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,69 @@
+use std::path::Path;
+use std::process;
+
+use crate::template::answers::{self, Answers, Verdict};
+use crate::template::run_multi::run_multi;
+use crate::template::runner::RunConfig;
+use crate::template::{all_days, Day, PuzzleId, Year};
+
+pub fn handle(year: Option<Year>, config: &RunConfig, store: bool) {
+    let days_to_run = all_days()
+        .filter(|day| Path::new(&PuzzleId::new(year, *day).bin_path()).exists())
+        .collect();
+
+    let run = run_multi(year, &days_to_run, config);
+
+    let mut verdicts: Vec<(Day, u8, Verdict)> = vec![];
+
+    for day in all_days().filter(|day| days_to_run.contains(day)) {
+        let puzzle = PuzzleId::new(year, day);
+        let mut recorded = Answers::read_from_file(puzzle);
+        let mut has_new_answers = false;
+
+        for part in [1, 2] {
+            let actual = run
+                .results
+                .iter()
+                .find(|r| r.day == day && r.part == part)
+                .and_then(|r| r.answer.as_deref());
+
+            let verdict = Verdict::new(recorded.get(part), actual);
+
+            if let (
+                true,
+                Verdict::Missing {
+                    actual: Some(actual),
+                },
+            ) = (store, &verdict)
+            {
+                *recorded.part_mut(part) = Some(actual.clone());
+                has_new_answers = true;
+            }
+
+            verdicts.push((day, part, verdict));
+        }
+
+        if has_new_answers {
+            if let Err(e) = recorded.store_file(puzzle) {
+                eprintln!("Failed to store answers for day {day}: {e}");
+            }
+        }
+    }
+
+    println!();
+    answers::print_table(&verdicts);
+
+    if store {
+        println!("\nStored missing answers.");
+    }
+
+    let mismatches = verdicts
+        .iter()
+        .filter(|(_, _, v)| matches!(v, Verdict::Mismatch { .. }))
+        .count();
+
+    if mismatches > 0 {
+        eprintln!("\n{mismatches} part(s) do not match the recorded answer.");
+        process::exit(1);
+    }
+}
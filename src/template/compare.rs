@@ -0,0 +1,211 @@
+/// Module that compares a benchmark run against the timings stored in `data/timings.json`.
+use crate::template::timings::{format_nanos, Timings};
+use crate::template::{Day, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// The change in benchmark time of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartDelta {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: Option<f64>,
+    pub current_nanos: Option<f64>,
+}
+
+impl PartDelta {
+    /// Relative change in percent, [`None`] if either side is missing.
+    pub fn percent(&self) -> Option<f64> {
+        match (self.baseline_nanos, self.current_nanos) {
+            (Some(baseline), Some(current)) if baseline > 0.0 => {
+                Some((current - baseline) / baseline * 100.0)
+            }
+            _ => None,
+        }
+    }
+
+    /// A part regressed if it got slower by more than `threshold` percent,
+    /// or if it has a baseline but no current time, e.g. because it timed out, failed or did not run.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.is_missing() || self.percent().is_some_and(|x| x > threshold)
+    }
+
+    /// Whether a part with a baseline has no current time.
+    pub fn is_missing(&self) -> bool {
+        self.baseline_nanos.is_some() && self.current_nanos.is_none()
+    }
+
+    fn is_improvement(&self, threshold: f64) -> bool {
+        self.percent().is_some_and(|x| x < -threshold)
+    }
+}
+
+/// Pair up every part of `current` with its counterpart in `baseline`.
+/// Days of `baseline` that are missing from `current` are included, without a current time.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<PartDelta> {
+    let mut days: Vec<Day> = baseline
+        .data
+        .iter()
+        .chain(&current.data)
+        .map(|t| t.day)
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let mut deltas = vec![];
+
+    for day in days {
+        let stored = baseline.data.iter().find(|t| t.day == day);
+        let timing = current.data.iter().find(|t| t.day == day);
+
+        for part in [1, 2] {
+            let current_nanos = timing.and_then(|t| t.part_nanos(part));
+            let baseline_nanos = stored.and_then(|t| t.part_nanos(part));
+
+            if current_nanos.is_some() || baseline_nanos.is_some() {
+                deltas.push(PartDelta {
+                    day,
+                    part,
+                    baseline_nanos,
+                    current_nanos,
+                });
+            }
+        }
+    }
+
+    deltas.sort_unstable_by_key(|d| (d.day, d.part));
+    deltas
+}
+
+fn format_delta(delta: &PartDelta) -> (String, String) {
+    match (delta.baseline_nanos, delta.current_nanos, delta.percent()) {
+        (Some(baseline), Some(current), Some(percent)) => {
+            let sign = if current < baseline { "-" } else { "+" };
+            (
+                format!("{sign}{}", format_nanos(Some((current - baseline).abs()))),
+                format!("{percent:+.1}%"),
+            )
+        }
+        _ if delta.is_missing() => ("-".into(), "missing".into()),
+        _ => ("-".into(), "-".into()),
+    }
+}
+
+/// Print a table of per-part deltas. Parts that changed by more than `threshold` percent are colored.
+pub fn print_table(deltas: &[PartDelta], threshold: f64) {
+    println!(
+        "{ANSI_BOLD}{:<5} {:<6} {:>12} {:>12} {:>12} {:>9}{ANSI_RESET}",
+        "Day", "Part", "Baseline", "Current", "Δ", "Δ%"
+    );
+
+    for delta in deltas {
+        let (abs, percent) = format_delta(delta);
+
+        let color = if delta.is_regression(threshold) {
+            ANSI_RED
+        } else if delta.is_improvement(threshold) {
+            ANSI_GREEN
+        } else {
+            ""
+        };
+
+        println!(
+            "{color}{:<5} {:<6} {:>12} {:>12} {:>12} {:>9}{ANSI_RESET}",
+            delta.day.to_string(),
+            delta.part,
+            format_nanos(delta.baseline_nanos),
+            format_nanos(delta.current_nanos),
+            abs,
+            percent
+        );
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, format_delta};
+    use crate::{day, template::timings::Timing, template::timings::Timings};
+
+    fn timing(day: crate::template::Day, part_1: Option<&str>, part_2: Option<&str>) -> Timing {
+        Timing {
+            part_1: part_1.map(Into::into),
+            part_2: part_2.map(Into::into),
+            ..Timing::new(day)
+        }
+    }
+
+    #[test]
+    fn compares_parts() {
+        let baseline = Timings {
+            data: vec![
+                timing(day!(1), Some("10.0ms"), Some("20.0ms")),
+                timing(day!(2), Some("1.0µs"), None),
+            ],
+        };
+        let current = Timings {
+            data: vec![
+                timing(day!(2), Some("2.0µs"), Some("5.0ns")),
+                timing(day!(1), Some("9.0ms"), Some("20.0ms")),
+            ],
+        };
+
+        let deltas = compare(&baseline, &current);
+        assert_eq!(deltas.len(), 4);
+
+        assert_eq!(deltas[0].day, day!(1));
+        assert_eq!(deltas[0].percent(), Some(-10.0));
+        assert_eq!(deltas[0].is_regression(5.0), false);
+
+        assert_eq!(deltas[1].percent(), Some(0.0));
+
+        assert_eq!(deltas[2].day, day!(2));
+        assert_eq!(deltas[2].percent(), Some(100.0));
+        assert_eq!(deltas[2].is_regression(5.0), true);
+        assert_eq!(deltas[2].is_regression(150.0), false);
+
+        assert_eq!(deltas[3].baseline_nanos, None);
+        assert_eq!(deltas[3].is_regression(0.0), false);
+    }
+
+    #[test]
+    fn flags_missing_parts() {
+        let baseline = Timings {
+            data: vec![
+                timing(day!(1), Some("10.0ms"), Some("20.0ms")),
+                timing(day!(2), Some("1.0µs"), None),
+            ],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), Some("10.0ms"), Some("TIMEOUT"))],
+        };
+
+        let deltas = compare(&baseline, &current);
+        assert_eq!(deltas.len(), 3);
+
+        assert_eq!(deltas[0].is_regression(5.0), false);
+
+        assert_eq!(deltas[1].current_nanos, None);
+        assert_eq!(deltas[1].is_regression(5.0), true);
+        assert_eq!(
+            format_delta(&deltas[1]),
+            ("-".to_string(), "missing".to_string())
+        );
+
+        assert_eq!(deltas[2].day, day!(2));
+        assert_eq!(deltas[2].is_regression(5.0), true);
+    }
+
+    #[test]
+    fn formats_deltas() {
+        let baseline = Timings {
+            data: vec![timing(day!(1), Some("10.0ms"), None)],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), Some("9.0ms"), None)],
+        };
+
+        let deltas = compare(&baseline, &current);
+        assert_eq!(
+            format_delta(&deltas[0]),
+            ("-1.0ms".to_string(), "-10.0%".to_string())
+        );
+    }
+}
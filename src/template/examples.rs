@@ -0,0 +1,92 @@
+/// Example files annotated with their expected answers, e.g. `expected_part_one = 22` in `data/examples/18.toml`.
+///
+/// `build.rs` discovers every example of a day (`NN.txt` and `NN-<name>.txt`) and `solution!` includes one test per
+/// file and annotated part, so adding an example with its answers does not require changes to the solution.
+use std::path::Path;
+
+use crate::template::params::Params;
+use crate::template::part_result::PartResult;
+use crate::template::runner::{read_input, RunOptions};
+
+/// Key of the expected answer of a part in an example's sidecar.
+pub fn expected_key(part: u8) -> &'static str {
+    match part {
+        1 => "expected_part_one",
+        _ => "expected_part_two",
+    }
+}
+
+/// Run a part of a solution against an example file and assert that it returns the expected answer.
+pub fn check(run: fn(&str, &Params, &RunOptions) -> Vec<PartResult>, path: &str, part: u8) {
+    let (input, params) =
+        read_input(Path::new(path)).unwrap_or_else(|e| panic!("could not read {path}: {e}"));
+
+    let expected: String = params
+        .get(expected_key(part))
+        .unwrap_or_else(|e| panic!("could not read expected answer of {path}: {e}"));
+
+    let options = RunOptions {
+        part: Some(part),
+        ..Default::default()
+    };
+
+    let result = run(&input, &params, &options)
+        .into_iter()
+        .find(|result| result.part == part);
+
+    if let Some(error) = result.as_ref().and_then(|result| result.error.as_ref()) {
+        panic!("part {part} of {path} failed: {error}");
+    }
+
+    let answer = result.and_then(|result| result.answer);
+
+    assert_eq!(
+        answer.as_deref(),
+        Some(expected.as_str()),
+        "wrong answer for part {part} of {path}"
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::check;
+    use crate::day;
+    use crate::template::{params::Params, part_result::PartResult, runner::RunOptions};
+
+    fn run(input: &str, _: &Params, options: &RunOptions) -> Vec<PartResult> {
+        [1, 2]
+            .into_iter()
+            .filter(|part| options.part.is_none_or(|p| p == *part))
+            .map(|part| PartResult {
+                answer: Some(format!("{}", input.trim().len() * usize::from(part))),
+                ..PartResult::new(day!(1), part)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn checks_expected_answers() {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("01.txt"), "abc\n").unwrap();
+        fs::write(
+            dir.join("01.toml"),
+            "expected_part_one = 3\nexpected_part_two = \"6\"\n",
+        )
+        .unwrap();
+
+        let path = dir.join("01.txt").to_string_lossy().into_owned();
+        check(run, &path, 1);
+        check(run, &path, 2);
+
+        fs::write(dir.join("01.toml"), "expected_part_one = 4\n").unwrap();
+        let wrong = std::panic::catch_unwind(|| check(run, &path, 1));
+        assert!(wrong.is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
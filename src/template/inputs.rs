@@ -0,0 +1,249 @@
+/// Alternative puzzle inputs, e.g. inputs of other accounts or community-provided edge cases.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{data_dir, part_result::PartResult, PuzzleId, ANSI_BOLD, ANSI_RESET};
+
+/// Inputs passed to a solution as `--input <path>` (repeatable) or `--inputs <dir>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Files(Vec<PathBuf>),
+    /// Every file in a folder, in alphabetical order. Parameter sidecars are skipped, see [`crate::template::params`].
+    Dir(PathBuf),
+}
+
+impl InputSource {
+    /// Read the input source from command-line arguments, if any.
+    pub fn from_args(args: &[String]) -> Option<Self> {
+        let values = |name: &str| -> Vec<PathBuf> {
+            args.windows(2)
+                .filter(|w| w[0] == name)
+                .map(|w| PathBuf::from(&w[1]))
+                .collect()
+        };
+
+        if let Some(dir) = values("--inputs").pop() {
+            return Some(InputSource::Dir(dir));
+        }
+
+        let files = values("--input");
+        (!files.is_empty()).then_some(InputSource::Files(files))
+    }
+
+    /// Convert the input source back to command-line arguments for a child invocation.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Files(files) => files
+                .iter()
+                .flat_map(|f| ["--input".into(), f.to_string_lossy().into_owned()])
+                .collect(),
+            InputSource::Dir(dir) => vec!["--inputs".into(), dir.to_string_lossy().into_owned()],
+        }
+    }
+
+    /// The input files to run.
+    pub fn paths(&self) -> Result<Vec<PathBuf>, io::Error> {
+        match self {
+            InputSource::Files(files) => Ok(files.clone()),
+            InputSource::Dir(dir) => list_files(dir),
+        }
+    }
+}
+
+/// The example of a day, run instead of the puzzle input when passed as `--example` or `--example-part <n>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Example {
+    /// The main example, e.g. `data/examples/01.txt`.
+    Default,
+    /// The example of a part, e.g. `data/examples/01-2.txt`.
+    Part(u8),
+}
+
+impl Example {
+    /// Read the example selection from command-line arguments, if any.
+    pub fn from_args(args: &[String]) -> Option<Self> {
+        let part = args
+            .windows(2)
+            .find(|w| w[0] == "--example-part")
+            .and_then(|w| w[1].parse().ok());
+
+        match part {
+            Some(part) => Some(Example::Part(part)),
+            None => args
+                .iter()
+                .any(|x| x == "--example")
+                .then_some(Example::Default),
+        }
+    }
+
+    /// The part an example is specific to, only that part is run against it.
+    pub fn part(self) -> Option<u8> {
+        match self {
+            Example::Default => None,
+            Example::Part(part) => Some(part),
+        }
+    }
+
+    /// Convert the example selection back to command-line arguments for a child invocation.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Example::Default => vec!["--example".into()],
+            Example::Part(part) => vec!["--example-part".into(), part.to_string()],
+        }
+    }
+
+    /// Path of the example file of a puzzle.
+    pub fn path(&self, puzzle: PuzzleId) -> PathBuf {
+        match self {
+            Example::Default => puzzle.data_path("examples"),
+            Example::Part(part) => data_dir(puzzle.year)
+                .join("examples")
+                .join(format!("{}-{part}.txt", puzzle.day)),
+        }
+    }
+}
+
+/// Path of the file a puzzle runs against: the selected example, or the puzzle input.
+pub fn input_path(puzzle: PuzzleId, example: Option<Example>) -> PathBuf {
+    example.map_or_else(
+        || puzzle.data_path("inputs"),
+        |example| example.path(puzzle),
+    )
+}
+
+fn list_files(dir: &Path) -> Result<Vec<PathBuf>, io::Error> {
+    let mut files = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    files.retain(|path| path.is_file() && path.extension().is_none_or(|ext| ext != "toml"));
+    files.sort();
+    Ok(files)
+}
+
+/// Print a table with the answers and times of every part per input.
+pub fn print_table(runs: &[(PathBuf, Vec<PartResult>)]) {
+    println!(
+        "{ANSI_BOLD}{:<30} {:<20} {:>10} {:<20} {:>10}{ANSI_RESET}",
+        "Input", "Part 1", "Time", "Part 2", "Time"
+    );
+
+    for (path, results) in runs {
+        let cell = |part: u8| {
+            results.iter().find(|r| r.part == part).map_or_else(
+                || ("-".to_string(), "-".to_string()),
+                |r| {
+                    (
+                        r.answer.clone().unwrap_or_else(|| "✖".into()),
+                        format!("{:.1?}", r.duration),
+                    )
+                },
+            )
+        };
+
+        let (part_1, time_1) = cell(1);
+        let (part_2, time_2) = cell(2);
+
+        println!(
+            "{:<30} {:<20} {:>10} {:<20} {:>10}",
+            path.file_name()
+                .map_or_else(|| path.to_string_lossy(), |n| n.to_string_lossy()),
+            part_1,
+            time_1,
+            part_2,
+            time_2
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use super::{input_path, Example, InputSource};
+    use crate::day;
+    use crate::template::PuzzleId;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_input_args() {
+        assert_eq!(InputSource::from_args(&args("01 --time")), None);
+        assert_eq!(
+            InputSource::from_args(&args("01 --input a.txt --input b.txt")),
+            Some(InputSource::Files(vec!["a.txt".into(), "b.txt".into()]))
+        );
+        assert_eq!(
+            InputSource::from_args(&args("01 --inputs data/alt --input a.txt")),
+            Some(InputSource::Dir("data/alt".into()))
+        );
+    }
+
+    #[test]
+    fn roundtrips_args() {
+        let source = InputSource::Files(vec!["a.txt".into(), "b.txt".into()]);
+        assert_eq!(InputSource::from_args(&source.to_args()), Some(source));
+
+        let source = InputSource::Dir("data/alt".into());
+        assert_eq!(InputSource::from_args(&source.to_args()), Some(source));
+    }
+
+    #[test]
+    fn parses_example_args() {
+        assert_eq!(Example::from_args(&args("01 --time")), None);
+        assert_eq!(
+            Example::from_args(&args("01 --example")),
+            Some(Example::Default)
+        );
+        assert_eq!(
+            Example::from_args(&args("01 --example-part 2")),
+            Some(Example::Part(2))
+        );
+
+        let example = Example::Part(2);
+        assert_eq!(Example::from_args(&example.to_args()), Some(example));
+        assert_eq!(example.part(), Some(2));
+        assert_eq!(Example::Default.part(), None);
+    }
+
+    #[test]
+    fn resolves_example_paths() {
+        let puzzle = PuzzleId::from(day!(1));
+        assert_eq!(
+            input_path(puzzle, None),
+            PathBuf::from("data/inputs/01.txt")
+        );
+        assert_eq!(
+            input_path(puzzle, Some(Example::Default)),
+            PathBuf::from("data/examples/01.txt")
+        );
+        assert_eq!(
+            input_path(puzzle, Some(Example::Part(2))),
+            PathBuf::from("data/examples/01-2.txt")
+        );
+    }
+
+    #[test]
+    fn lists_files_in_dir() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("b.txt"), "").unwrap();
+        fs::write(dir.join("a.txt"), "").unwrap();
+        fs::write(dir.join("a.toml"), "").unwrap();
+
+        let paths = InputSource::Dir(dir.clone()).paths().unwrap();
+        let names: Vec<PathBuf> = paths
+            .iter()
+            .map(|p| p.strip_prefix(&dir).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(names, vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
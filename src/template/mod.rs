@@ -1,68 +1,160 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod alloc_counter;
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
+pub mod inputs;
+pub mod memory;
+pub mod params;
+pub mod part_result;
+pub mod registry;
+pub mod report;
 pub mod runner;
 
 pub use day::*;
+pub use year::*;
 
+mod answers;
+pub mod bench_stats;
+mod compare;
 mod day;
+mod markdown;
+mod readme;
 mod readme_benchmarks;
 mod run_multi;
+mod submissions;
 mod timings;
+mod timings_history;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: impl Into<PuzzleId>) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(puzzle.into().data_path(folder));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: impl Into<PuzzleId>, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
+    let puzzle = puzzle.into();
     let filepath = cwd
-        .join("data")
+        .join(data_dir(puzzle.year))
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY` and `PUZZLE` and sets up the input and runner for each part.
+/// Also creates the constant `SOLUTION`, which the main binary uses to run the day in-process.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Solutions kept per year pass their year, e.g. `solution!(1, year = 2023)`.
+/// Solutions that take parameters pass their type last, e.g. `solution!(18, params = Params)`, see [`params`].
+///
+/// Tests are generated for every example file of the day that is annotated with its expected answers, see [`examples`].
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr $(, year = $year:expr)? $(, params = $params:ty)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [$($params)?], [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr, 1 $(, year = $year:expr)? $(, params = $params:ty)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [$($params)?], [part_one, 1]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($day:expr, 2 $(, year = $year:expr)? $(, params = $params:ty)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [$($params)?], [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@year) => { None };
+    (@year $year:expr) => { Some($crate::year!($year)) };
+
+    (@run $input:ident, $params:ident, $options:ident, [], $( [$func:expr, $part:expr] )*) => {{
+        let _ = $params;
+        let mut results = vec![];
+        $(
+            if $options.part.is_none_or(|part| part == $part) {
+                results.push(run_part_with($func, $input, PUZZLE, $part, $options));
+            }
+        )*
+        results
+    }};
+    (@run $input:ident, $params:ident, $options:ident, [$type:ty], $( [$func:expr, $part:expr] )*) => {{
+        use $crate::template::params::FromParams;
+        let params = match <$type>::from_params($params) {
+            Ok(params) => params,
+            Err(e) => {
+                eprintln!("Invalid parameters: {e}");
+                return vec![];
+            }
+        };
+        let mut results = vec![];
+        $(
+            if $options.part.is_none_or(|part| part == $part) {
+                results.push(run_part_with(|input| $func(input, &params), $input, PUZZLE, $part, $options));
+            }
+        )*
+        results
+    }};
+
+    (@examples $day:expr,) => { concat!(env!("OUT_DIR"), "/examples/", $day, ".rs") };
+    (@examples $day:expr, $year:expr) => { concat!(env!("OUT_DIR"), "/examples/", $year, "-", $day, ".rs") };
+
+    (@impl $day:expr, [$($year:expr)?], [$($params:ty)?], $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The current puzzle, i.e. the day and its year if any.
+        const PUZZLE: $crate::template::PuzzleId =
+            $crate::template::PuzzleId::new($crate::solution!(@year $($year)?), DAY);
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
-        fn main() {
+        #[cfg(all(feature = "count-alloc", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc_counter::CountingAlloc =
+            $crate::template::alloc_counter::CountingAlloc;
+
+        fn run_parts(
+            input: &str,
+            params: &$crate::template::params::Params,
+            options: &$crate::template::runner::RunOptions,
+        ) -> Vec<$crate::template::part_result::PartResult> {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            $crate::solution!(@run input, params, options, [$($params)?], $( [$func, $part] )*)
+        }
+
+        /// The registry entry of this solution.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            puzzle: PUZZLE,
+            run: run_parts,
+        };
+
+        /// One test per example file and part with an expected answer, generated by `build.rs`.
+        #[cfg(test)]
+        mod example_tests {
+            include!($crate::solution!(@examples $day, $($year)?));
+        }
+
+        // when linked into the main binary as a module, `main` is never called.
+        #[allow(dead_code)]
+        fn main() {
+            $crate::template::runner::run_main(PUZZLE, run_parts);
         }
     };
 }
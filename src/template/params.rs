@@ -0,0 +1,186 @@
+/// Per-input parameters, read from a sidecar file next to the input, e.g. `data/examples/18.toml`.
+///
+/// Sidecars hold flat `key = value` pairs, with `#` comments and optionally quoted strings.
+/// This lets one binary run examples and real inputs that differ in constants such as a grid size.
+use std::{collections::HashMap, fmt::Display, fs, io, path::Path, str::FromStr};
+
+use crate::template::PuzzleId;
+
+/// The raw parameters of an input. Missing sidecars result in no parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(HashMap<String, String>);
+
+impl Params {
+    /// Read the sidecar of an input file, i.e. the file with the same name and a `.toml` extension.
+    pub fn read_for(input_path: &Path) -> Result<Self, ParamsError> {
+        match fs::read_to_string(input_path.with_extension("toml")) {
+            Ok(contents) => Self::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(ParamsError::IO(e)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, ParamsError> {
+        let mut params = HashMap::new();
+
+        for line in contents.lines() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParamsError::Syntax(line.to_string()))?;
+
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            params.insert(key.trim().to_string(), value.to_string());
+        }
+
+        Ok(Self(params))
+    }
+
+    /// A required parameter.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<T, ParamsError> {
+        let value = self
+            .0
+            .get(key)
+            .ok_or_else(|| ParamsError::Missing(key.to_string()))?;
+
+        value.parse().map_err(|_| ParamsError::Invalid {
+            key: key.to_string(),
+            value: value.clone(),
+        })
+    }
+
+    /// An optional parameter, `default` if not present.
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> Result<T, ParamsError> {
+        match self.get(key) {
+            Err(ParamsError::Missing(_)) => Ok(default),
+            x => x,
+        }
+    }
+}
+
+/// Cut a line at its `#` comment, if any. A `#` inside a quoted value does not start a comment.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+#[derive(Debug)]
+pub enum ParamsError {
+    Syntax(String),
+    Missing(String),
+    Invalid { key: String, value: String },
+    IO(io::Error),
+}
+
+impl Display for ParamsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamsError::Syntax(line) => write!(f, "expected `key = value`, got `{line}`."),
+            ParamsError::Missing(key) => write!(f, "missing parameter `{key}`."),
+            ParamsError::Invalid { key, value } => {
+                write!(f, "invalid value `{value}` for parameter `{key}`.")
+            }
+            ParamsError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// A typed parameter struct, passed to the parts of solutions declared with `solution!(<day>, params = <type>)`.
+pub trait FromParams: Sized {
+    fn from_params(params: &Params) -> Result<Self, ParamsError>;
+}
+
+/// Helper function that reads the typed parameters of a day's file in a data folder, e.g. for tests.
+#[must_use]
+pub fn read_params<P: FromParams>(folder: &str, puzzle: impl Into<PuzzleId>) -> P {
+    Params::read_for(&puzzle.into().data_path(folder))
+        .and_then(|params| P::from_params(&params))
+        .unwrap_or_else(|e| panic!("could not read parameters: {e}"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{FromParams, Params, ParamsError};
+
+    struct Grid {
+        width: usize,
+        name: String,
+    }
+
+    impl FromParams for Grid {
+        fn from_params(params: &Params) -> Result<Self, ParamsError> {
+            Ok(Self {
+                width: params.get_or("width", 71)?,
+                name: params.get("name")?,
+            })
+        }
+    }
+
+    #[test]
+    fn parses_sidecars() {
+        let params =
+            Params::parse("# example\nwidth = 7 # grid size\n\nname = \"small grid\"\n").unwrap();
+        let grid = Grid::from_params(&params).unwrap();
+        assert_eq!(grid.width, 7);
+        assert_eq!(grid.name, "small grid");
+    }
+
+    #[test]
+    fn keeps_hashes_in_quoted_values() {
+        let params = Params::parse("name = \"#1 # not a comment\" # comment\n").unwrap();
+        let grid = Grid::from_params(&params).unwrap();
+        assert_eq!(grid.name, "#1 # not a comment");
+    }
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let grid = Grid::from_params(&Params::parse("name = x").unwrap()).unwrap();
+        assert_eq!(grid.width, 71);
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert!(matches!(
+            Params::parse("width 7"),
+            Err(ParamsError::Syntax(_))
+        ));
+        assert!(matches!(
+            Grid::from_params(&Params::default()),
+            Err(ParamsError::Missing(_))
+        ));
+        assert!(matches!(
+            Grid::from_params(&Params::parse("width = seven\nname = x").unwrap()),
+            Err(ParamsError::Invalid { .. })
+        ));
+    }
+
+    #[test]
+    fn handles_missing_sidecars() {
+        assert_eq!(
+            Params::read_for(Path::new("data/inputs/missing.txt")).unwrap(),
+            Params::default()
+        );
+    }
+}
@@ -0,0 +1,274 @@
+/// Machine-readable result records exchanged between solution binaries and the `run_multi` driver.
+/// When the `AOC_RESULTS_FILE` env var is set, every solution part appends one JSON line to that file.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{bench_stats::BenchStats, memory::MemoryStats, Day};
+
+/// Name of the env var that selects the file records are appended to.
+pub const RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+/// Represents the outcome of running a single solution part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    /// Heap usage of the part, only measured with `--dhat`.
+    pub memory: Option<MemoryStats>,
+    /// The error returned by a part that returns a `Result`, see [`crate::template::runner::PartOutput`].
+    pub error: Option<String>,
+}
+
+impl PartResult {
+    /// The record of a single run of a part without an answer, e.g. to fill in with struct update syntax.
+    pub fn new(day: Day, part: u8) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            duration: Duration::ZERO,
+            samples: 1,
+            stats: None,
+            memory: None,
+            error: None,
+        }
+    }
+
+    /// Append the record to the file selected by `AOC_RESULTS_FILE`. Does nothing if the var is unset.
+    pub fn emit(&self) -> Result<(), io::Error> {
+        let Some(path) = env::var_os(RESULTS_FILE_ENV) else {
+            return Ok(());
+        };
+
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{line}")
+    }
+
+    /// Read all records from a results file. If not present, returns no records.
+    pub fn read_all(path: &Path) -> Result<Vec<Self>, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse_all(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Parse newline-delimited JSON records, skipping blank lines.
+    pub fn parse_all(contents: &str) -> Result<Vec<Self>, String> {
+        contents
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| {
+                let json = JsonValue::from_str(l).or(Err("not a valid JSON record."))?;
+                PartResult::try_from(&json)
+            })
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "memory".into(),
+            match &value.memory {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "error".into(),
+            match &value.error {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected result to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected result.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .filter(|p| *p == 1.0 || *p == 2.0)
+            .ok_or("Expected result.part to be 1 or 2.")? as u8;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?;
+
+        let duration_nanos = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected result.duration_nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected result.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let memory = match json.get("memory") {
+            Some(v) if !v.is_null() => Some(MemoryStats::try_from(v)?),
+            _ => None,
+        };
+
+        let error = match json.get("error") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected result.error to be null or string.")?,
+            ),
+            _ => None,
+        };
+
+        Ok(PartResult {
+            day,
+            part,
+            answer: answer.cloned(),
+            duration: Duration::from_nanos(duration_nanos as u64),
+            samples: samples as u128,
+            stats,
+            memory,
+            error,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::PartResult;
+    use crate::day;
+    use crate::template::{bench_stats::BenchStats, memory::MemoryStats};
+
+    #[test]
+    fn roundtrips_records() {
+        let result = PartResult {
+            day: day!(17),
+            part: 2,
+            answer: Some("Part 1: 1 (2ns @ 3 samples)".into()),
+            duration: Duration::from_nanos(74_130),
+            samples: 3,
+            stats: BenchStats::from_samples(&[
+                Duration::from_nanos(70_000),
+                Duration::from_nanos(74_130),
+                Duration::from_nanos(80_000),
+            ]),
+            memory: Some(MemoryStats {
+                allocations: 4,
+                total_bytes: 1024,
+                peak_bytes: 512,
+            }),
+            error: None,
+        };
+        let line = JsonValue::from(&result).stringify().unwrap();
+        let parsed = PartResult::parse_all(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(parsed, vec![result.clone(), result]);
+    }
+
+    #[test]
+    fn handles_missing_answers() {
+        let parsed = PartResult::parse_all(
+            r#"{ "day": "01", "part": 1, "answer": null, "duration_nanos": 10, "samples": 1 }"#,
+        )
+        .unwrap();
+        assert_eq!(parsed[0].answer, None);
+        assert_eq!(parsed[0].duration, Duration::from_nanos(10));
+        assert_eq!(parsed[0].stats, None);
+        assert_eq!(parsed[0].memory, None);
+        assert_eq!(parsed[0].error, None);
+    }
+
+    #[test]
+    fn roundtrips_errors() {
+        let parsed = PartResult::parse_all(
+            r#"{ "day": "13", "part": 2, "answer": null, "duration_nanos": 10, "samples": 1, "error": "line 3, column 1: expected 6 numbers, found 4." }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parsed[0].error.as_deref(),
+            Some("line 3, column 1: expected 6 numbers, found 4.")
+        );
+
+        let line = JsonValue::from(&parsed[0]).stringify().unwrap();
+        assert_eq!(PartResult::parse_all(&line).unwrap(), parsed);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_part() {
+        PartResult::parse_all(
+            r#"{ "day": "01", "part": 3, "answer": null, "duration_nanos": 10, "samples": 1 }"#,
+        )
+        .unwrap();
+    }
+}
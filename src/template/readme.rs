@@ -0,0 +1,310 @@
+/// Module that generates the readme: a stars table from the recorded answers and submissions, and the benchmark table.
+///
+/// If `README.template.md` exists, it is rendered to `README.md`, replacing its placeholders:
+/// `{{year}}`, `{{stars}}`, `{{total_stars}}`, `{{benchmarks}}` and `{{total_time}}`.
+/// Otherwise the tables between the `<!--- stars table --->` and `<!--- benchmarking table --->` markers of the
+/// readme are updated, leaving everything else (e.g. a table maintained by `advent-readme-stars`) untouched.
+///
+/// Every year of the multi-year layout gets its own readme, e.g. `README-2023.md`, so that it does not overwrite `README.md`.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::answers::Answers;
+use crate::template::aoc_client::SubmissionOutcome;
+use crate::template::readme_benchmarks::{self, locate_table, Error};
+use crate::template::submissions::Submission;
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, PuzzleId, Year};
+
+pub static README_PATH: &str = "README.md";
+pub static TEMPLATE_PATH: &str = "README.template.md";
+
+static STARS_MARKER: &str = "<!--- stars table --->";
+
+/// Links of a day to its puzzle on the website and to its solution file.
+pub struct Links {
+    /// The year of the file layout, see [`PuzzleId`].
+    pub year: Option<Year>,
+    /// The year of the puzzles, used for links to the website.
+    pub aoc_year: Option<Year>,
+}
+
+impl Links {
+    pub fn new(year: Option<Year>) -> Self {
+        Self {
+            year,
+            aoc_year: year.or_else(Year::from_env),
+        }
+    }
+
+    /// The day, linked to its puzzle if the year is known, e.g. `[Day 1](https://adventofcode.com/2024/day/1)`.
+    pub fn day(&self, day: Day) -> String {
+        match self.aoc_year {
+            Some(year) => format!(
+                "[Day {}](https://adventofcode.com/{year}/day/{})",
+                day.into_inner(),
+                day.into_inner()
+            ),
+            None => format!("Day {}", day.into_inner()),
+        }
+    }
+
+    /// The solution file of a day, e.g. `[01.rs](./src/bin/01.rs)`.
+    pub fn solution(&self, day: Day) -> String {
+        let puzzle = PuzzleId::new(self.year, day);
+        format!("[{}.rs]({})", puzzle.bin_name(), puzzle.bin_path())
+    }
+}
+
+/// The readme of a year, `README.md` for the single-year layout.
+pub fn readme_path(year: Option<Year>) -> PathBuf {
+    match year {
+        Some(year) => PathBuf::from(format!("README-{year}.md")),
+        None => PathBuf::from(README_PATH),
+    }
+}
+
+/// The content of a year's readme before its first update, with the markers of both tables.
+fn new_readme(year: Year) -> String {
+    format!(
+        "# Advent of Code {year}\n\n{STARS_MARKER}\n{STARS_MARKER}\n\n{}\n{}\n",
+        readme_benchmarks::MARKER,
+        readme_benchmarks::MARKER
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The stars earned on a day, per part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub parts: [bool; 2],
+}
+
+impl Stars {
+    pub fn count(&self) -> usize {
+        self.parts.iter().filter(|&&star| star).count()
+    }
+}
+
+/// A part earns a star if its answer was recorded, see `cargo verify --store`, or accepted by the website.
+/// Days without a solution or a star are left out.
+pub fn collect_stars(year: Option<Year>) -> Vec<Stars> {
+    let submissions = Submission::read_all_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read submissions: {e}");
+        vec![]
+    });
+
+    all_days()
+        .map(|day| {
+            let puzzle = PuzzleId::new(year, day);
+            let answers = Answers::read_from_file(puzzle);
+            let parts = [1, 2].map(|part| {
+                answers.get(part).is_some()
+                    || submissions.iter().any(|s| {
+                        s.day == day && s.part == part && s.outcome == SubmissionOutcome::Correct
+                    })
+            });
+            (puzzle, Stars { day, parts })
+        })
+        .filter(|(puzzle, stars)| stars.count() > 0 || Path::new(&puzzle.bin_path()).exists())
+        .map(|(_, stars)| stars)
+        .collect()
+}
+
+/// The stars table, followed by the total number of stars.
+pub fn stars_table(stars: &[Stars], links: &Links) -> String {
+    let mut lines: Vec<String> = vec![
+        "| Day | Part 1 | Part 2 | Solution |".into(),
+        "| :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    for day in stars {
+        let [part_1, part_2] = day.parts.map(|star| if star { "⭐" } else { "" });
+        lines.push(format!(
+            "| {} | {part_1} | {part_2} | {} |",
+            links.day(day.day),
+            links.solution(day.day)
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {}⭐**", total_stars(stars)));
+
+    lines.join("\n")
+}
+
+fn total_stars(stars: &[Stars]) -> usize {
+    stars.iter().map(Stars::count).sum()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Replace the placeholders of a readme template.
+pub fn render(template: &str, stars: &[Stars], timings: &Timings, links: &Links) -> String {
+    let year = links.aoc_year.map(|y| y.to_string()).unwrap_or_default();
+
+    template
+        .replace("{{year}}", &year)
+        .replace("{{stars}}", &stars_table(stars, links))
+        .replace("{{total_stars}}", &total_stars(stars).to_string())
+        .replace(
+            "{{benchmarks}}",
+            &readme_benchmarks::benchmark_table(timings, links),
+        )
+        .replace(
+            "{{total_time}}",
+            &format!("{:.2}ms", timings.total_millis()),
+        )
+}
+
+/// Update the stars table between its markers, if the readme has them.
+fn update_stars(s: &mut String, stars: &[Stars], links: &Links) -> Result<(), Error> {
+    if !s.contains(STARS_MARKER) {
+        return Ok(());
+    }
+
+    let positions = locate_table(s, STARS_MARKER)?;
+    let table = [
+        STARS_MARKER.into(),
+        "## Stars".into(),
+        String::new(),
+        stars_table(stars, links),
+        STARS_MARKER.into(),
+    ]
+    .join("\n");
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Generate the readme of a year from the stored timings, see the module documentation.
+/// Returns the path of the written readme.
+pub fn update(year: Option<Year>) -> Result<PathBuf, Error> {
+    let links = Links::new(year);
+    let stars = collect_stars(year);
+    let timings = Timings::read_from_file(year);
+    let path = readme_path(year);
+
+    let readme = if Path::new(TEMPLATE_PATH).exists() {
+        render(
+            &fs::read_to_string(TEMPLATE_PATH)?,
+            &stars,
+            &timings,
+            &links,
+        )
+    } else {
+        let mut readme = match year {
+            Some(year) if !path.exists() => new_readme(year),
+            _ => fs::read_to_string(&path)?,
+        };
+        update_stars(&mut readme, &stars, &links)?;
+        readme_benchmarks::update_content(&mut readme, &timings, &links)?;
+        readme
+    };
+
+    fs::write(&path, readme)?;
+    Ok(path)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{
+        new_readme, readme_path, render, stars_table, update_stars, Links, Stars, STARS_MARKER,
+    };
+    use crate::day;
+    use crate::template::readme_benchmarks;
+    use crate::template::timings::Timings;
+    use crate::template::Year;
+
+    fn links() -> Links {
+        Links {
+            year: None,
+            aoc_year: Year::new(2024),
+        }
+    }
+
+    fn stars() -> Vec<Stars> {
+        vec![
+            Stars {
+                day: day!(1),
+                parts: [true, true],
+            },
+            Stars {
+                day: day!(2),
+                parts: [true, false],
+            },
+        ]
+    }
+
+    #[test]
+    fn formats_stars_table() {
+        let expected = [
+            "| Day | Part 1 | Part 2 | Solution |",
+            "| :---: | :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ | [01.rs](./src/bin/01.rs) |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ |  | [02.rs](./src/bin/02.rs) |",
+            "",
+            "**Total: 3⭐**",
+        ]
+        .join("\n");
+        assert_eq!(stars_table(&stars(), &links()), expected);
+    }
+
+    #[test]
+    fn links_solutions_of_years() {
+        let links = Links::new(Year::new(2023));
+        assert_eq!(
+            links.day(day!(5)),
+            "[Day 5](https://adventofcode.com/2023/day/5)"
+        );
+        assert_eq!(
+            links.solution(day!(5)),
+            "[2023-05.rs](./src/bin/2023-05.rs)"
+        );
+    }
+
+    #[test]
+    fn keeps_readmes_of_years_apart() {
+        assert_eq!(readme_path(None), PathBuf::from("README.md"));
+        assert_eq!(
+            readme_path(Year::new(2023)),
+            PathBuf::from("README-2023.md")
+        );
+
+        let mut readme = new_readme(Year::new(2023).unwrap());
+        update_stars(&mut readme, &stars(), &links()).unwrap();
+        readme_benchmarks::update_content(&mut readme, &Timings::default(), &links()).unwrap();
+        assert!(readme.starts_with("# Advent of Code 2023\n"));
+        assert!(readme.contains("## Stars"));
+        assert!(readme.contains("## Benchmarks"));
+    }
+
+    #[test]
+    fn renders_template() {
+        let template = "# AoC {{year}}\n{{total_stars}} stars in {{total_time}}\n{{stars}}";
+        let rendered = render(template, &stars(), &Timings::default(), &links());
+        assert!(rendered.starts_with("# AoC 2024\n3 stars in 0.00ms\n| Day |"));
+        assert!(!rendered.contains("{{"));
+    }
+
+    #[test]
+    fn updates_stars_only_between_markers() {
+        let mut s = "# readme".to_string();
+        update_stars(&mut s, &stars(), &links()).unwrap();
+        assert_eq!(s, "# readme");
+
+        let mut s = format!("foo\n{STARS_MARKER}\n{STARS_MARKER}\nbar");
+        update_stars(&mut s, &stars(), &links()).unwrap();
+        update_stars(&mut s, &stars(), &links()).unwrap();
+        assert_eq!(s.matches(STARS_MARKER).count(), 2);
+        assert_eq!(s.matches("## Stars").count(), 1);
+        assert!(s.ends_with(&format!("**Total: 3⭐**\n{STARS_MARKER}\nbar")));
+    }
+}
@@ -1,11 +1,15 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, io};
 
+use crate::template::memory::format_bytes;
+use crate::template::readme::Links;
 use crate::template::timings::Timings;
-use crate::template::Day;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+pub static MARKER: &str = "<!--- benchmarking table --->";
+
+/// Width of the bar of the day with the largest share of the total time.
+const BAR_WIDTH: usize = 20;
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -14,6 +18,15 @@ pub enum Error {
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -21,17 +34,12 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,76 +60,129 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// The benchmark table with a bar of each day's share of the total time, followed by the total.
+/// The peak heap usage of every part is shown as well if any was measured, see `cargo time --dhat`.
+pub fn benchmark_table(timings: &Timings, links: &Links) -> String {
+    let total_nanos: f64 = timings.data.iter().map(|t| t.total_nanos).sum();
+    let max_nanos = timings
+        .data
+        .iter()
+        .map(|t| t.total_nanos)
+        .fold(0_f64, f64::max);
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let has_memory = timings
+        .data
+        .iter()
+        .any(|t| t.part_1_memory.is_some() || t.part_2_memory.is_some());
+
+    let mut lines: Vec<String> = if has_memory {
+        vec![
+            "| Day | Solution | Part 1 | Part 2 | Peak heap | Share |".into(),
+            "| :---: | :---: | :---: | :---: | :---: | :--- |".into(),
+        ]
+    } else {
+        vec![
+            "| Day | Solution | Part 1 | Part 2 | Share |".into(),
+            "| :---: | :---: | :---: | :---: | :--- |".into(),
+        ]
+    };
+
+    for timing in &timings.data {
+        let mut cells = vec![
+            links.day(timing.day),
+            links.solution(timing.day),
+            format!("`{}`", timing.part_1.as_deref().unwrap_or("-")),
+            format!("`{}`", timing.part_2.as_deref().unwrap_or("-")),
+        ];
+
+        if has_memory {
+            let peak = |part: u8| {
+                timing
+                    .part_memory(part)
+                    .map_or_else(|| "-".into(), |m| format_bytes(m.peak_bytes))
+            };
+            cells.push(format!("`{}` / `{}`", peak(1), peak(2)));
+        }
+
+        cells.push(share_bar(timing.total_nanos, total_nanos, max_nanos));
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(format!("**Total: {:.2}ms**", timings.total_millis()));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+/// A bar of a day's share of the total time, scaled to the largest share, e.g. `████ 12.5%`.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn share_bar(nanos: f64, total_nanos: f64, max_nanos: f64) -> String {
+    if total_nanos <= 0.0 || max_nanos <= 0.0 {
+        return "-".into();
+    }
+
+    let width = ((nanos / max_nanos) * BAR_WIDTH as f64).round() as usize;
+    let bar = "█".repeat(width.max(usize::from(nanos > 0.0)));
+    format!("{bar} {:.1}%", nanos / total_nanos * 100.0)
+}
+
+fn construct_table(prefix: &str, timings: &Timings, links: &Links) -> String {
+    [
+        MARKER.into(),
+        format!("{prefix} Benchmarks"),
+        String::new(),
+        benchmark_table(timings, links),
+        MARKER.into(),
+    ]
+    .join("\n")
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+pub fn update_content(s: &mut String, timings: &Timings, links: &Links) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", timings, links);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
+    use super::{benchmark_table, update_content, MARKER};
+    use crate::template::memory::MemoryStats;
+    use crate::template::readme::Links;
+    use crate::template::Year;
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
+    fn links() -> Links {
+        Links {
+            year: None,
+            aoc_year: Year::new(2024),
+        }
+    }
+
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -131,28 +192,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), &links()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), &links()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), &links()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), &links()).unwrap();
+        update_content(&mut s, &get_mock_timings(), &links()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,24 +221,39 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), &links()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Solution | Part 1 | Part 2 | Share |",
+            "| :---: | :---: | :---: | :---: | :--- |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | [01.rs](./src/bin/01.rs) | `10ms` | `20ms` | ███████ 15.8% |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | [02.rs](./src/bin/02.rs) | `30ms` | `40ms` | ████████████████ 36.8% |",
+            "| [Day 4](https://adventofcode.com/2024/day/4) | [04.rs](./src/bin/04.rs) | `40ms` | `50ms` | ████████████████████ 47.4% |",
             "",
-            "**Total: 190.00ms**",
+            "**Total: 190000.00ms**",
             "<!--- benchmarking table --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn shows_peak_heap_if_measured() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_2_memory = Some(MemoryStats {
+            allocations: 2,
+            total_bytes: 4096,
+            peak_bytes: 1536,
+        });
+
+        let table = benchmark_table(&timings, &links());
+        assert!(table.starts_with("| Day | Solution | Part 1 | Part 2 | Peak heap | Share |"));
+        assert!(table.contains("| `20ms` | `-` / `1.5 KiB` | "));
+        assert!(table.contains("| `50ms` | `-` / `-` | "));
+    }
 }
@@ -0,0 +1,25 @@
+/// Registry of the solutions linked into the main binary, used to run days without spawning a child process.
+/// Solutions are only linked in when the main binary is built with the `in-process` feature, so that a day that
+/// does not compile yet only breaks its own binary.
+use std::sync::OnceLock;
+
+use crate::template::{params::Params, part_result::PartResult, runner::RunOptions, PuzzleId};
+
+/// A solution as registered by the [`solution!`](crate::solution) macro.
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    /// Runs every part of the solution against an input and its parameters.
+    pub run: fn(&str, &Params, &RunOptions) -> Vec<PartResult>,
+}
+
+static SOLUTIONS: OnceLock<&'static [Solution]> = OnceLock::new();
+
+/// Register the solutions linked into the current binary. Only the first call has an effect.
+pub fn register(solutions: &'static [Solution]) {
+    let _ = SOLUTIONS.set(solutions);
+}
+
+/// Look up the registered solution for a puzzle.
+pub fn get(puzzle: PuzzleId) -> Option<&'static Solution> {
+    SOLUTIONS.get()?.iter().find(|s| s.puzzle == puzzle)
+}
@@ -0,0 +1,459 @@
+/// Consolidated reports of `cargo all` and `cargo time`, written as `--format json|csv|markdown` to `--output <file>` or stdout.
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    part_result::PartResult,
+    run_multi::{Abort, MultiRun},
+    Day,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl ReportFormat {
+    /// Guess the format from the extension of an output file, e.g. `report.csv`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()?.to_str()?.parse().ok()
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = ReportFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(ReportFormat::Json),
+            "csv" => Ok(ReportFormat::Csv),
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            _ => Err(ReportFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`ReportFormat`].
+#[derive(Debug)]
+pub struct ReportFormatFromStrError;
+
+impl Error for ReportFormatFromStrError {}
+
+impl Display for ReportFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `json`, `csv` or `markdown`")
+    }
+}
+
+/// Where and how to write a report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportConfig {
+    pub format: ReportFormat,
+    /// The file to write the report to, stdout if not set.
+    pub output: Option<PathBuf>,
+}
+
+impl ReportConfig {
+    /// Write a report, see [`ReportConfig::output`].
+    pub fn write(&self, report: &Report) -> Result<(), io::Error> {
+        let contents = match self.format {
+            ReportFormat::Json => report.to_json(),
+            ReportFormat::Csv => report.to_csv(),
+            ReportFormat::Markdown => report.to_markdown(),
+        };
+
+        match &self.output {
+            Some(path) => fs::write(path, contents),
+            None => {
+                println!("\n{contents}");
+                Ok(())
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    /// Both parts returned an answer.
+    Solved,
+    /// One part returned an answer.
+    Partial,
+    Unsolved,
+}
+
+impl DayStatus {
+    /// The status of a day, from the result records of a run.
+    pub fn from_results(results: &[PartResult], day: Day) -> Self {
+        let answers = results
+            .iter()
+            .filter(|r| r.day == day && r.answer.is_some())
+            .count();
+
+        match answers {
+            0 => DayStatus::Unsolved,
+            1 => DayStatus::Partial,
+            _ => DayStatus::Solved,
+        }
+    }
+}
+
+impl Display for DayStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayStatus::Solved => f.write_str("solved"),
+            DayStatus::Partial => f.write_str("partial"),
+            DayStatus::Unsolved => f.write_str("unsolved"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartReport {
+    pub answer: Option<String>,
+    pub duration: Option<Duration>,
+    pub aborted: Option<Abort>,
+    /// Why the part failed, either the error it returned or why its day could not be built or run.
+    pub error: Option<String>,
+}
+
+impl PartReport {
+    /// The time of the part as formatted by the runner, the reason if it was aborted, `FAILED` if it failed, `-` if it did not run.
+    fn time(&self) -> String {
+        match (&self.aborted, &self.error, &self.duration) {
+            (Some(abort), _, _) => abort.to_string(),
+            (None, Some(_), _) => "FAILED".into(),
+            (None, None, Some(duration)) => format!("{duration:.1?}"),
+            (None, None, None) => "-".into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayReport {
+    pub day: Day,
+    pub status: DayStatus,
+    pub parts: [PartReport; 2],
+}
+
+/// Answers, status and timings of every day of a run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    pub fn new(run: &MultiRun) -> Self {
+        let days = run
+            .days
+            .iter()
+            .map(|&day| {
+                let part = |part: u8| {
+                    let result = run
+                        .results
+                        .iter()
+                        .find(|r: &&PartResult| r.day == day && r.part == part);
+
+                    let day_error = || {
+                        run.failed
+                            .iter()
+                            .find(|(d, _)| *d == day)
+                            .map(|(_, error)| error.clone())
+                    };
+
+                    PartReport {
+                        answer: result.and_then(|r| r.answer.clone()),
+                        // failed parts are not timed, like in the stored timings.
+                        duration: result.filter(|r| r.error.is_none()).map(|r| r.duration),
+                        aborted: run
+                            .aborted
+                            .iter()
+                            .find(|(d, p, _)| *d == day && *p == part)
+                            .map(|(_, _, abort)| *abort),
+                        error: result.map_or_else(day_error, |r| r.error.clone()),
+                    }
+                };
+
+                DayReport {
+                    day,
+                    status: DayStatus::from_results(&run.results, day),
+                    parts: [part(1), part(2)],
+                }
+            })
+            .collect();
+
+        Self { days }
+    }
+
+    /// Number of days with both parts solved.
+    pub fn solved(&self) -> usize {
+        self.days
+            .iter()
+            .filter(|d| d.status == DayStatus::Solved)
+            .count()
+    }
+
+    /// Total time of a part (1 or 2) across days, or of both parts if `None`.
+    pub fn total(&self, part: Option<u8>) -> Duration {
+        self.days
+            .iter()
+            .flat_map(|d| d.parts.iter().enumerate())
+            .filter(|(i, _)| part.is_none_or(|part| usize::from(part) == i + 1))
+            .filter_map(|(_, p)| p.duration)
+            .sum()
+    }
+
+    pub fn to_json(&self) -> String {
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+        let string_or_null = |s: Option<String>| s.map_or(JsonValue::Null, JsonValue::String);
+
+        let days = self
+            .days
+            .iter()
+            .map(|day| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert("day".into(), JsonValue::String(day.day.to_string()));
+                map.insert("status".into(), JsonValue::String(day.status.to_string()));
+
+                for (i, part) in day.parts.iter().enumerate() {
+                    let mut part_map: HashMap<String, JsonValue> = HashMap::new();
+                    part_map.insert("answer".into(), string_or_null(part.answer.clone()));
+                    part_map.insert(
+                        "duration_nanos".into(),
+                        part.duration.map_or(JsonValue::Null, nanos),
+                    );
+                    part_map.insert(
+                        "aborted".into(),
+                        string_or_null(part.aborted.map(|a| a.to_string())),
+                    );
+                    part_map.insert("error".into(), string_or_null(part.error.clone()));
+                    map.insert(format!("part_{}", i + 1), JsonValue::Object(part_map));
+                }
+
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut totals: HashMap<String, JsonValue> = HashMap::new();
+        #[allow(clippy::cast_precision_loss)]
+        totals.insert("days".into(), JsonValue::Number(self.days.len() as f64));
+        #[allow(clippy::cast_precision_loss)]
+        totals.insert("solved".into(), JsonValue::Number(self.solved() as f64));
+        totals.insert("part_1_nanos".into(), nanos(self.total(Some(1))));
+        totals.insert("part_2_nanos".into(), nanos(self.total(Some(2))));
+        totals.insert("duration_nanos".into(), nanos(self.total(None)));
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("days".into(), JsonValue::Array(days));
+        map.insert("totals".into(), JsonValue::Object(totals));
+
+        JsonValue::Object(map)
+            .format()
+            .unwrap_or_else(|e| format!("{{\"error\": \"{e}\"}}"))
+    }
+
+    /// One row per day, followed by a row with the totals. Times are in nanoseconds.
+    pub fn to_csv(&self) -> String {
+        let nanos = |d: Option<Duration>| d.map_or_else(String::new, |d| d.as_nanos().to_string());
+
+        let mut lines = vec!["day,status,part_1_answer,part_1_nanos,part_1_aborted,part_1_error,part_2_answer,part_2_nanos,part_2_aborted,part_2_error".to_string()];
+
+        for day in &self.days {
+            let mut cells = vec![day.day.to_string(), day.status.to_string()];
+            for part in &day.parts {
+                cells.push(csv_escape(part.answer.as_deref().unwrap_or_default()));
+                cells.push(nanos(part.duration));
+                cells.push(part.aborted.map(|a| a.to_string()).unwrap_or_default());
+                cells.push(csv_escape(part.error.as_deref().unwrap_or_default()));
+            }
+            lines.push(cells.join(","));
+        }
+
+        lines.push(format!(
+            "total,{} of {} solved,,{},,,,{},,",
+            self.solved(),
+            self.days.len(),
+            nanos(Some(self.total(Some(1)))),
+            nanos(Some(self.total(Some(2)))),
+        ));
+
+        lines.join("\n")
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut lines = vec![
+            "| Day | Status | Part 1 | Time | Part 2 | Time |".to_string(),
+            "| :---: | :---: | :---: | :---: | :---: | :---: |".to_string(),
+        ];
+
+        for day in &self.days {
+            let answer = |part: &PartReport| match (&part.answer, &part.error) {
+                (Some(answer), _) => format!("`{answer}`"),
+                (None, Some(error)) => format!("✖ {}", error.replace('|', "\\|")),
+                (None, None) => "-".into(),
+            };
+
+            lines.push(format!(
+                "| {} | {} | {} | `{}` | {} | `{}` |",
+                day.day.into_inner(),
+                day.status,
+                answer(&day.parts[0]),
+                day.parts[0].time(),
+                answer(&day.parts[1]),
+                day.parts[1].time(),
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!(
+            "**{} of {} days solved, total: {:.2}ms**",
+            self.solved(),
+            self.days.len(),
+            self.total(None).as_secs_f64() * 1000.0
+        ));
+
+        lines.join("\n")
+    }
+}
+
+/// Quote a CSV cell if it contains a separator, a quote or a line break.
+fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::Path, time::Duration};
+
+    use tinyjson::JsonValue;
+
+    use super::{DayStatus, Report, ReportFormat};
+    use crate::day;
+    use crate::template::{
+        part_result::PartResult,
+        run_multi::{Abort, MultiRun},
+    };
+
+    fn record(day: u8, part: u8, answer: &str, millis: u64) -> PartResult {
+        PartResult {
+            answer: Some(answer.into()),
+            duration: Duration::from_millis(millis),
+            ..PartResult::new(crate::template::Day::new(day).unwrap(), part)
+        }
+    }
+
+    fn report() -> Report {
+        Report::new(&MultiRun {
+            days: vec![day!(1), day!(2), day!(3), day!(4)],
+            results: vec![
+                record(1, 1, "42", 1),
+                record(1, 2, "6,1", 2),
+                record(2, 1, "7", 3),
+                PartResult {
+                    answer: None,
+                    error: Some("line 1, column 1: expected a number".into()),
+                    ..record(3, 1, "", 4)
+                },
+            ],
+            timings: None,
+            aborted: vec![(day!(2), 2, Abort::Timeout)],
+            failed: vec![(day!(4), "build failed".into())],
+        })
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("csv".parse::<ReportFormat>().unwrap(), ReportFormat::Csv);
+        assert_eq!(
+            ReportFormat::from_path(Path::new("out/report.md")),
+            Some(ReportFormat::Markdown)
+        );
+        assert!("xml".parse::<ReportFormat>().is_err());
+    }
+
+    #[test]
+    fn builds_report() {
+        let report = report();
+        let statuses: Vec<DayStatus> = report.days.iter().map(|d| d.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                DayStatus::Solved,
+                DayStatus::Partial,
+                DayStatus::Unsolved,
+                DayStatus::Unsolved
+            ]
+        );
+        assert_eq!(report.days[1].parts[1].aborted, Some(Abort::Timeout));
+        assert_eq!(
+            report.days[2].parts[0].error.as_deref(),
+            Some("line 1, column 1: expected a number")
+        );
+        assert_eq!(
+            report.days[3].parts[1].error.as_deref(),
+            Some("build failed")
+        );
+        assert_eq!(report.solved(), 1);
+        assert_eq!(report.total(Some(1)), Duration::from_millis(4));
+        assert_eq!(report.total(None), Duration::from_millis(6));
+    }
+
+    #[test]
+    fn writes_csv() {
+        let csv = report().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[1], "01,solved,42,1000000,,,\"6,1\",2000000,,");
+        assert_eq!(lines[2], "02,partial,7,3000000,,,,,TIMEOUT,");
+        assert_eq!(
+            lines[3],
+            "03,unsolved,,,,\"line 1, column 1: expected a number\",,,,"
+        );
+        assert_eq!(lines[5], "total,1 of 4 solved,,4000000,,,,2000000,,");
+    }
+
+    #[test]
+    fn writes_markdown() {
+        let markdown = report().to_markdown();
+        assert!(markdown.contains("| 2 | partial | `7` | `3.0ms` | - | `TIMEOUT` |"));
+        assert!(markdown
+            .contains("| 4 | unsolved | ✖ build failed | `FAILED` | ✖ build failed | `FAILED` |"));
+        assert!(markdown.ends_with("**1 of 4 days solved, total: 6.00ms**"));
+    }
+
+    #[test]
+    fn writes_json() {
+        let json: JsonValue = report().to_json().parse().unwrap();
+        assert_eq!(json["totals"]["solved"], JsonValue::Number(1.0));
+        assert_eq!(
+            json["days"][0]["part_2"]["answer"],
+            JsonValue::String("6,1".into())
+        );
+        assert_eq!(
+            json["days"][1]["part_2"]["aborted"],
+            JsonValue::String("TIMEOUT".into())
+        );
+        assert_eq!(
+            json["days"][3]["part_1"]["error"],
+            JsonValue::String("build failed".into())
+        );
+    }
+}
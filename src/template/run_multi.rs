@@ -1,192 +1,634 @@
-use std::{collections::HashSet, io};
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    io, panic,
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    inputs::{self, Example},
+    part_result::PartResult,
+    registry::{self, Solution},
+    report::DayStatus,
+    runner::{read_input, BenchConfig, RunConfig, RunOptions},
+    Day, PuzzleId, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// The combined output of running a set of days.
+pub struct MultiRun {
+    /// The days that were run, in order.
+    pub days: Vec<Day>,
+    /// Result records of every part that was run.
+    pub results: Vec<PartResult>,
+    /// Benchmark timings, only present for timed runs.
+    pub timings: Option<Timings>,
+    /// Parts that were aborted for exceeding a limit.
+    pub aborted: Vec<(Day, u8, Abort)>,
+    /// Days that could not be built or run, with the reason.
+    pub failed: Vec<(Day, String)>,
+}
+
+/// Why a part was aborted, see [`Limits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Abort {
+    Timeout,
+    OutOfMemory,
+}
+
+impl Display for Abort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Abort::Timeout => f.write_str("TIMEOUT"),
+            Abort::OutOfMemory => f.write_str("OOM"),
+        }
+    }
+}
+
+/// The outcome of running a single day.
+#[derive(Debug, Default)]
+pub struct DayRun {
+    pub results: Vec<PartResult>,
+    /// Set if the day was killed for exceeding a limit.
+    pub aborted: Option<Abort>,
+    /// Set if the day could not be built or run.
+    pub error: Option<String>,
+    /// Buffered output of the day, empty if it was printed directly.
+    pub output: String,
+}
+
+impl DayRun {
+    fn failed(error: impl Display) -> Self {
+        DayRun {
+            error: Some(error.to_string()),
+            ..DayRun::default()
+        }
+    }
+}
+
+/// Run the solutions for a set of days of a year. Solutions are benched when `config.bench` is set.
+/// With `config.example`, every day runs against its example instead of its puzzle input.
+///
+/// Solutions registered in the main binary are run in-process, others (or all, if `config.subprocess` is set) are run as a child process.
+/// Days also run as child processes when `config.limits` are set, so that they can be killed when they exceed them.
+///
+/// With more than one job, up to `config.jobs` days run concurrently as child processes. Their output is buffered and printed in day order.
+pub fn run_multi(year: Option<Year>, days_to_run: &HashSet<Day>, config: &RunConfig) -> MultiRun {
+    let RunConfig {
+        profile,
+        subprocess,
+        example,
+        jobs,
+        ..
+    } = *config;
+    let (bench, limits) = (config.bench.as_ref(), &config.limits);
+
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut all_results: Vec<PartResult> = vec![];
+    let mut aborted: Vec<(Day, u8, Abort)> = vec![];
+    let mut failed_days: Vec<(Day, String)> = vec![];
 
-    let mut need_space = false;
+    let start = Instant::now();
+    let is_parallel = jobs > 1;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let puzzles: Vec<PuzzleId> = all_days()
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .map(|day| PuzzleId::new(year, day))
+        .collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let in_process = |puzzle: PuzzleId| {
+        registry::get(puzzle).filter(|_| {
+            profile.runs_in_process() && !subprocess && !limits.is_set() && !is_parallel
+        })
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    // solutions that run as child processes are built up-front, in a single cargo invocation.
+    let to_build: Vec<PuzzleId> = puzzles
+        .iter()
+        .copied()
+        .filter(|puzzle| in_process(*puzzle).is_none())
+        .collect();
+    let executables = child_commands::build(&to_build, profile);
+
+    let run_day = |puzzle: PuzzleId| match in_process(puzzle) {
+        Some(solution) => DayRun {
+            results: run_in_process(solution, bench, example),
+            ..DayRun::default()
+        },
+        None => match &executables {
+            Err(e) => DayRun::failed(format_args!("could not build: {e}")),
+            Ok(executables) => match executables.get(&puzzle.bin_name()) {
+                Some(executable) => child_commands::run_solution(
+                    executable,
+                    puzzle,
+                    bench,
+                    example,
+                    limits,
+                    is_parallel,
+                )
+                .unwrap_or_else(DayRun::failed),
+                // scaffolded solutions without an executable did not compile.
+                None if Path::new(&puzzle.bin_path()).exists() => DayRun::failed("build failed"),
+                None => DayRun::default(),
+            },
+        },
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+    let mut report = |puzzle: PuzzleId, run: DayRun| {
+        let day = puzzle.day;
+        print!("{}", run.output);
+
+        // the part that was running when the child was killed, i.e. the one after the last reported part.
+        let abort = run
+            .aborted
+            .map(|abort| (run.results.last().map_or(1, |r| r.part + 1), abort));
+
+        if let Some((part, abort)) = abort {
+            println!("Part {part}: {ANSI_BOLD}{abort}{ANSI_RESET}");
+            aborted.push((day, part, abort));
+        }
+
+        if let Some(error) = run.error {
+            println!("{ANSI_RED}Failed: {error}{ANSI_RESET}");
+            failed_days.push((day, error));
+        } else if run.results.is_empty() && abort.is_none() {
+            println!("Not solved.");
+        } else {
+            let mut val = child_commands::parse_exec_time(&run.results, day);
+            match abort {
+                Some((1, abort)) => val.part_1 = Some(abort.to_string()),
+                Some((2, abort)) => val.part_2 = Some(abort.to_string()),
+                _ => {}
             }
+            timings.push(val);
+        }
+
+        all_results.extend(run.results);
+    };
+
+    let print_header = |i: usize, puzzle: PuzzleId| {
+        if i > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+        println!("------");
+    };
+
+    if is_parallel {
+        run_parallel(&puzzles, jobs, run_day, |i, puzzle, run| {
+            print_header(i, puzzle);
+            report(puzzle, run);
         });
+    } else {
+        for (i, puzzle) in puzzles.iter().enumerate() {
+            print_header(i, *puzzle);
+            report(*puzzle, run_day(*puzzle));
+        }
+    }
 
-    if is_timed {
+    let timings = bench.map(|_| {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+        timings
+    });
+
+    if !aborted.is_empty() {
+        println!("\n{ANSI_BOLD}Aborted:{ANSI_RESET}");
+        for (day, part, abort) in &aborted {
+            println!("Day {day} part {part}: {abort}");
+        }
+    }
+
+    let failed: Vec<&PartResult> = all_results.iter().filter(|r| r.error.is_some()).collect();
+
+    if !failed.is_empty() || !failed_days.is_empty() {
+        println!("\n{ANSI_BOLD}Failed:{ANSI_RESET}");
+        for (day, error) in &failed_days {
+            println!("Day {day}: {error}");
+        }
+        for result in &failed {
+            let error = result.error.as_deref().unwrap_or_default();
+            println!("Day {} part {}: {error}", result.day, result.part);
+        }
+    }
+
+    if is_parallel {
+        let solved = puzzles
+            .iter()
+            .filter(|puzzle| DayStatus::from_results(&all_results, puzzle.day) == DayStatus::Solved)
+            .count();
+
+        println!(
+            "\n{ANSI_BOLD}Summary:{ANSI_RESET} {solved} of {} day(s) solved, {} day(s) failed, {} part(s) failed, {} part(s) aborted, {ANSI_ITALIC}{:.2?} wall-clock with {jobs} jobs{ANSI_RESET}",
+            puzzles.len(),
+            failed_days.len(),
+            failed.len(),
+            aborted.len(),
+            start.elapsed()
+        );
+    }
+
+    MultiRun {
+        days: puzzles.iter().map(|puzzle| puzzle.day).collect(),
+        results: all_results,
+        timings,
+        aborted,
+        failed: failed_days,
+    }
+}
+
+/// Run up to `jobs` items concurrently, passing each outcome to `report` in the order of `items` as soon as all previous items completed.
+fn run_parallel<T: Copy + Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    run: impl Fn(T) -> R + Sync,
+    mut report: impl FnMut(usize, T, R),
+) {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let tx = tx.clone();
+            let (next, run) = (&next, &run);
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                if tx.send((i, run(*item))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut pending: BTreeMap<usize, R> = BTreeMap::new();
+        let mut next_to_report = 0;
+
+        for (i, outcome) in rx {
+            pending.insert(i, outcome);
+
+            while let Some(outcome) = pending.remove(&next_to_report) {
+                report(next_to_report, items[next_to_report], outcome);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
+/// Run a registered solution in the current process.
+/// A missing input is reported and treated as not solved, a panicking part is recorded as failed.
+fn run_in_process(
+    solution: &Solution,
+    bench: Option<&BenchConfig>,
+    example: Option<Example>,
+) -> Vec<PartResult> {
+    let input_path = inputs::input_path(solution.puzzle, example);
+
+    let (input, params) = match read_input(&input_path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!(
+                "Could not read input file \"{}\": {e}",
+                input_path.display()
+            );
+            return vec![];
+        }
+    };
+
+    let mut results = vec![];
+
+    // parts run one by one, so that the records of finished parts are kept when a later one panics.
+    for part in [1, 2] {
+        if example.and_then(Example::part).is_some_and(|p| p != part) {
+            continue;
+        }
+
+        let options = RunOptions {
+            bench: bench.cloned(),
+            part: Some(part),
+            example,
+            ..RunOptions::default()
+        };
+
+        match panic::catch_unwind(|| (solution.run)(&input, &params, &options)) {
+            Ok(records) => results.extend(records),
+            Err(payload) => {
+                // like a child process, the day stops at the panicking part.
+                results.push(PartResult {
+                    error: Some(format!("panicked: {}", panic_message(payload.as_ref()))),
+                    ..PartResult::new(solution.puzzle.day, part)
+                });
+                break;
+            }
+        }
     }
+
+    results
+}
+
+/// The message of a panic, if it was raised with one.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
 }
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
-    BrokenPipe,
+    Parser(String),
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse result records: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting their result records.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{Abort, DayRun, Error};
+    use crate::template::{
+        inputs::Example,
+        part_result::{PartResult, RESULTS_FILE_ENV},
+        runner::{BenchConfig, BuildProfile, Limits},
+        Day, PuzzleId,
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        collections::HashMap,
+        env, fs,
+        io::Read,
+        path::{Path, PathBuf},
+        process::{self, Child, Command, Stdio},
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// How often a child is checked against its limits.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Run the executable of a solution and collect the result records it emits.
+    /// With `capture`, its output is buffered instead of being passed through.
+    pub fn run_solution(
+        executable: &Path,
+        puzzle: PuzzleId,
+        bench: Option<&BenchConfig>,
+        example: Option<Example>,
+        limits: &Limits,
+        capture: bool,
+    ) -> Result<DayRun, Error> {
+        let mut args: Vec<String> = vec![];
+
+        if let Some(bench) = bench {
+            // mirror `--time` flag and benchmark settings to child invocations.
+            args.push("--time".into());
+            args.extend(bench.to_args());
+        }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if let Some(example) = example {
+            args.extend(example.to_args());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // the child appends one JSON line per part to this file.
+        let results_path =
+            env::temp_dir().join(format!("aoc-results-{}-{puzzle}.jsonl", process::id()));
+        let _ = fs::remove_file(&results_path);
 
-        if is_release {
-            args.push("--release");
-        }
+        let mut cmd = Command::new(executable);
+        cmd.args(&args).env(RESULTS_FILE_ENV, &results_path);
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if capture {
+            cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        let mut child = cmd.spawn()?;
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        // pipes are drained while the child runs, so that it never blocks on a full pipe.
+        let readers = [
+            child.stdout.take().map(spawn_reader),
+            child.stderr.take().map(spawn_reader),
+        ];
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let aborted = supervise(&mut child, &results_path, limits)?;
 
-        let mut output = vec![];
+        let output = readers
+            .into_iter()
+            .flatten()
+            .map(|reader| reader.join().unwrap_or_default())
+            .collect();
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let results = PartResult::read_all(&results_path).map_err(Error::Parser);
+        let _ = fs::remove_file(&results_path);
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
-
-        thread.join().unwrap();
-        cmd.wait()?;
+        // e.g. a record truncated by killing the child. Its output is kept, the day is reported as failed.
+        let (results, error) = match results {
+            Ok(results) => (results, None),
+            Err(e) => (vec![], Some(e.to_string())),
+        };
 
-        Ok(output)
+        Ok(DayRun {
+            results,
+            aborted,
+            error,
+            output,
+        })
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    fn spawn_reader(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+        thread::spawn(move || {
+            let mut buf = vec![];
+            let _ = pipe.read_to_end(&mut buf);
+            String::from_utf8_lossy(&buf).into_owned()
+        })
+    }
 
-        output
+    /// Build the bins of a set of puzzles, returning the paths of their executables by bin name.
+    /// Puzzles that have not been scaffolded yet or that fail to build are left out.
+    pub fn build(
+        puzzles: &[PuzzleId],
+        profile: BuildProfile,
+    ) -> Result<HashMap<String, PathBuf>, Error> {
+        let bins: Vec<String> = puzzles
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+            .filter(|puzzle| Path::new(&puzzle.bin_path()).exists())
+            .map(PuzzleId::bin_name)
+            .collect();
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        if bins.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut args: Vec<String> = vec![
+            "build".into(),
+            "--quiet".into(),
+            "--keep-going".into(),
+            "--message-format=json-render-diagnostics".into(),
+        ];
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+        for bin in bins {
+            args.push("--bin".into());
+            args.push(bin);
+        }
+
+        args.extend(profile.cargo_args());
+
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.parse::<JsonValue>().ok())
+            .filter_map(|json| {
+                let artifact = json.get::<HashMap<String, JsonValue>>()?;
+                let name = artifact
+                    .get("target")?
+                    .get::<HashMap<String, JsonValue>>()?
+                    .get("name")?
+                    .get::<String>()?;
+                let executable = artifact.get("executable")?.get::<String>()?;
+                Some((name.clone(), PathBuf::from(executable)))
             })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            .collect())
+    }
 
-                timings.total_nanos += nanos;
-            });
+    /// Wait for a child to exit, killing it once a part exceeds the timeout or the child exceeds the memory limit.
+    /// A part's time is measured from the start of the child or from the record of the previous part.
+    fn supervise(
+        child: &mut Child,
+        results_path: &Path,
+        limits: &Limits,
+    ) -> Result<Option<Abort>, Error> {
+        if !limits.is_set() {
+            child.wait()?;
+            return Ok(None);
+        }
 
-        timings
+        let mut records = 0;
+        let mut part_start = Instant::now();
+
+        loop {
+            if child.try_wait()?.is_some() {
+                return Ok(None);
+            }
+
+            let count = fs::read_to_string(results_path).map_or(0, |s| s.lines().count());
+            if count > records {
+                records = count;
+                part_start = Instant::now();
+            }
+
+            let abort = if limits.timeout.is_some_and(|t| part_start.elapsed() > t) {
+                Some(Abort::Timeout)
+            } else if limits
+                .max_memory
+                .is_some_and(|max| resident_memory(child.id()).is_some_and(|rss| rss > max))
+            {
+                Some(Abort::OutOfMemory)
+            } else {
+                None
+            };
+
+            if abort.is_some() {
+                child.kill()?;
+                child.wait()?;
+                return Ok(abort);
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Resident memory of a process in bytes. Only available on Linux.
+    fn resident_memory(pid: u32) -> Option<u64> {
+        fs::read_to_string(format!("/proc/{pid}/status"))
+            .ok()
+            .and_then(|status| parse_vm_rss(&status))
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// Parse the `VmRSS` line of `/proc/<pid>/status`, e.g. `VmRSS:  1024 kB`.
+    fn parse_vm_rss(status: &str) -> Option<u64> {
+        let line = status.lines().find(|l| l.starts_with("VmRSS:"))?;
+        let kilobytes: u64 = line
+            .trim_start_matches("VmRSS:")
+            .trim()
+            .trim_end_matches("kB")
+            .trim()
+            .parse()
+            .ok()?;
+        Some(kilobytes * 1024)
+    }
+
+    /// Build a [`super::Timing`] from the result records of a single day.
+    pub fn parse_exec_time(results: &[PartResult], day: Day) -> super::Timing {
+        let mut timings = super::Timing::new(day);
+
+        results.iter().filter(|r| r.day == day).for_each(|r| {
+            // failed parts are not benched, like aborted ones.
+            if r.error.is_some() {
+                match r.part {
+                    1 => timings.part_1 = Some("FAILED".into()),
+                    2 => timings.part_2 = Some("FAILED".into()),
+                    _ => {}
+                }
+                return;
+            }
+
+            let timing_str = format!("{:.1?}", r.duration);
+
+            match r.part {
+                1 => {
+                    timings.part_1 = Some(timing_str);
+                    timings.part_1_stats.clone_from(&r.stats);
+                    timings.part_1_memory = r.memory;
+                }
+                2 => {
+                    timings.part_2 = Some(timing_str);
+                    timings.part_2_stats.clone_from(&r.stats);
+                    timings.part_2_memory = r.memory;
+                }
+                _ => return,
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = r.duration.as_nanos() as f64;
+            timings.total_nanos += nanos;
+        });
+
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +647,96 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_vm_rss};
 
-        use crate::day;
+        use crate::{day, template::part_result::PartResult};
 
         #[test]
         fn parses_execution_times() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
+                &PartResult::parse_all(
+                    r#"{ "day": "01", "part": 1, "answer": "0", "duration_nanos": 74, "samples": 100000 }
+                       { "day": "01", "part": 2, "answer": "10", "duration_nanos": 74130000, "samples": 99999 }"#,
+                )
+                .unwrap(),
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
+        fn parses_with_patterns_in_answer() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
+                &PartResult::parse_all(
+                    r#"{ "day": "01", "part": 1, "answer": "@ @ @ ( ) ms (2s @ 5 samples)", "duration_nanos": 2000000000, "samples": 5 }
+                       { "day": "01", "part": 2, "answer": "10s", "duration_nanos": 100000000, "samples": 1 }"#,
+                )
+                .unwrap(),
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
+        fn parses_failed_parts() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
+                &PartResult::parse_all(
+                    r#"{ "day": "01", "part": 1, "answer": "0", "duration_nanos": 74, "samples": 1 }
+                       { "day": "01", "part": 2, "answer": null, "duration_nanos": 1000, "samples": 1, "error": "bad input" }"#,
+                )
+                .unwrap(),
                 day!(1),
             );
+            assert_approx_eq!(res.total_nanos, 74_f64);
+            assert_eq!(res.part_2.as_deref(), Some("FAILED"));
+            assert_eq!(res.part_nanos(2), None);
+        }
+
+        #[test]
+        fn parses_missing_parts() {
+            let res = parse_exec_time(&[], day!(1));
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_resident_memory() {
+            let status = "Name:\t01\nVmPeak:\t   20480 kB\nVmRSS:\t    1024 kB\nThreads:\t1\n";
+            assert_eq!(parse_vm_rss(status), Some(1024 * 1024));
+            assert_eq!(parse_vm_rss("Name:\t01\n"), None);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::run_parallel;
+
+    #[test]
+    fn reports_parallel_runs_in_order() {
+        let items = [30, 10, 20, 0, 5];
+        let mut reported = vec![];
+
+        run_parallel(
+            &items,
+            3,
+            |millis: u64| {
+                thread::sleep(Duration::from_millis(millis));
+                millis * 2
+            },
+            |i, item, outcome| reported.push((i, item, outcome)),
+        );
+
+        assert_eq!(
+            reported,
+            vec![(0, 30, 60), (1, 10, 20), (2, 20, 40), (3, 0, 0), (4, 5, 10)]
+        );
     }
 }
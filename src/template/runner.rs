@@ -1,65 +1,533 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
+use std::fs;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::path::Path;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
+use crate::template::alloc_counter::{self, AllocCount, AllocsPerIteration};
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, AocClientError, SubmissionOutcome};
+use crate::template::bench_stats::BenchStats;
+use crate::template::inputs::{self, Example, InputSource};
+use crate::template::memory::MemoryStats;
+use crate::template::params::Params;
+use crate::template::part_result::PartResult;
+use crate::template::submissions::{self, Submission};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, PuzzleId, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Benchmark settings, mirrored to solution binaries as `--warmup <n>`, `--target-time <ms>` and `--samples <n>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed iterations executed before sampling starts.
+    pub warmup: u32,
+    /// Approximate time spent sampling, used to derive the number of samples.
+    pub target_time: Duration,
+    /// Fixed number of samples, overrides `target_time` when set.
+    pub samples: Option<u128>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            target_time: Duration::from_secs(1),
+            samples: None,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Read the benchmark settings from command-line arguments, falling back to defaults.
+    pub fn from_args(args: &[String]) -> Self {
+        let value = |name: &str| {
+            args.iter()
+                .position(|x| x == name)
+                .and_then(|i| args.get(i + 1))
+                .and_then(|x| x.parse::<u64>().ok())
+        };
+
+        let defaults = Self::default();
+
+        Self {
+            warmup: value("--warmup")
+                .and_then(|x| u32::try_from(x).ok())
+                .unwrap_or(defaults.warmup),
+            target_time: value("--target-time")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.target_time),
+            samples: value("--samples").map(u128::from).or(defaults.samples),
+        }
+    }
+
+    /// Convert the settings back to command-line arguments for a child invocation.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--warmup".into(),
+            self.warmup.to_string(),
+            "--target-time".into(),
+            self.target_time.as_millis().to_string(),
+        ];
+
+        if let Some(samples) = self.samples {
+            args.push("--samples".into());
+            args.push(samples.to_string());
+        }
+
+        args
+    }
+
+    /// Number of samples to collect: the configured count, or enough to fill `target_time` (10 to 10000).
+    fn sample_count(&self, base_time: &Duration) -> u128 {
+        self.samples.unwrap_or_else(|| {
+            (self.target_time.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000)
+        })
+    }
+}
+
+/// Limits applied to every day of a run, passed as `--timeout <seconds>` and `--max-memory <MiB>`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock time a single part may take, including benchmarking.
+    pub timeout: Option<Duration>,
+    /// Resident memory a day may use, in bytes. Only enforced on Linux.
+    pub max_memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.max_memory.is_some()
+    }
+}
+
+/// How `cargo all`, `cargo time` and `cargo verify` run a set of days.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunConfig {
+    pub profile: BuildProfile,
+    /// Run every day as a child process, passed as `--subprocess`.
+    pub subprocess: bool,
+    /// Bench every part with these settings.
+    pub bench: Option<BenchConfig>,
+    /// Run every day against its example instead of its puzzle input.
+    pub example: Option<Example>,
+    pub limits: Limits,
+    /// Number of days to run concurrently, passed as `--jobs <n>`.
+    pub jobs: usize,
+}
+
+/// The cargo profile and features solutions are built with, passed as `--release` or `--dhat`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BuildProfile {
+    #[default]
+    Debug,
+    Release,
+    /// Optimized build with the `dhat-heap` feature, which measures the heap usage of every part.
+    Dhat,
+    /// Optimized build with the `count-alloc` feature, which counts the allocations of every benchmark iteration.
+    Alloc,
+}
+
+impl BuildProfile {
+    pub fn new(release: bool, dhat: bool) -> Self {
+        match (release, dhat) {
+            (_, true) => BuildProfile::Dhat,
+            (true, false) => BuildProfile::Release,
+            (false, false) => BuildProfile::Debug,
+        }
+    }
+
+    /// Arguments that select the profile in `cargo build` and `cargo run`.
+    pub fn cargo_args(self) -> Vec<String> {
+        match self {
+            BuildProfile::Debug => vec![],
+            BuildProfile::Release => vec!["--release".into()],
+            BuildProfile::Dhat => vec![
+                "--profile".into(),
+                "dhat".into(),
+                "--features".into(),
+                "dhat-heap".into(),
+            ],
+            BuildProfile::Alloc => vec![
+                "--release".into(),
+                "--features".into(),
+                "count-alloc".into(),
+            ],
+        }
+    }
+
+    /// Name of the profile, e.g. as recorded in the timing history.
+    pub fn name(self) -> &'static str {
+        match self {
+            BuildProfile::Debug => "debug",
+            BuildProfile::Release => "release",
+            BuildProfile::Dhat => "dhat",
+            BuildProfile::Alloc => "alloc",
+        }
+    }
+
+    /// Whether the solutions linked into the main binary match this profile, see [`crate::template::registry`].
+    /// That is only the case if the running binary was built with the same profile, e.g. a debug run of a release-built
+    /// main binary runs its days in child processes. Builds that declare a global allocator never link them in.
+    pub fn runs_in_process(self) -> bool {
+        let current = if cfg!(debug_assertions) {
+            BuildProfile::Debug
+        } else {
+            BuildProfile::Release
+        };
+
+        self == current
+    }
+}
+
+/// Options that control how solution parts are run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Bench every part with these settings, passed as `--time`.
+    pub bench: Option<BenchConfig>,
+    /// Submit the result of this part, passed as `--submit <part>`.
+    pub submit: Option<u8>,
+    /// Run against these inputs instead of the puzzle input, see [`InputSource`].
+    pub input: Option<InputSource>,
+    /// Only run this part, e.g. when checking an example that only has an answer for one part, or with `--example-part <n>`.
+    pub part: Option<u8>,
+    /// Run against an example instead of the puzzle input, passed as `--example` or `--example-part <n>`.
+    pub example: Option<Example>,
+}
+
+impl RunOptions {
+    /// Read the options from the command-line arguments of the current process.
+    pub fn from_env() -> Self {
+        Self::from_args(&env::args().collect::<Vec<_>>())
+    }
+
+    /// Read the options from command-line arguments.
+    pub fn from_args(args: &[String]) -> Self {
+        let bench = args
+            .iter()
+            .any(|x| x == "--time")
+            .then(|| BenchConfig::from_args(args));
+
+        let example = Example::from_args(args);
+
+        Self {
+            bench,
+            submit: parse_submit_arg(args),
+            input: InputSource::from_args(args),
+            part: example.and_then(Example::part),
+            example,
+        }
+    }
+}
+
+/// Entry point of a solution binary: runs every part against the puzzle input, or against each input passed on the command-line.
+pub fn run_main(puzzle: PuzzleId, run: fn(&str, &Params, &RunOptions) -> Vec<PartResult>) {
+    let mut options = RunOptions::from_env();
+
+    if options.example.is_some() && options.input.is_some() {
+        eprintln!("`--input` and `--inputs` can not be combined with `--example`.");
+        process::exit(1);
+    }
+
+    if options.example.is_some() && options.submit.take().is_some() {
+        eprintln!("Answers of examples are not submitted.");
+    }
+
+    let Some(source) = &options.input else {
+        match read_input(&inputs::input_path(puzzle, options.example)) {
+            Ok((input, params)) => {
+                run(&input, &params, &options);
+            }
+            Err(e) => panic!("could not open input file: {e}"),
+        }
+        return;
+    };
+
+    let paths = source.paths().unwrap_or_else(|e| {
+        eprintln!("Could not list input files: {e}");
+        process::exit(1);
+    });
+
+    if options.submit.is_some() {
+        eprintln!("Answers are not submitted when running against multiple inputs.");
+    }
+
+    let options = RunOptions {
+        submit: None,
+        ..options.clone()
+    };
+
+    let mut runs = vec![];
+
+    for path in paths {
+        println!("{ANSI_BOLD}{}{ANSI_RESET}", path.display());
+
+        match read_input(&path) {
+            Ok((input, params)) => runs.push((path, run(&input, &params, &options))),
+            Err(e) => eprintln!("Could not read input file: {e}"),
+        }
+
+        println!();
+    }
+
+    inputs::print_table(&runs);
+}
+
+/// Read an input file and the parameters of its sidecar.
+pub fn read_input(path: &Path) -> Result<(String, Params), String> {
+    let input = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let params = Params::read_for(path).map_err(|e| e.to_string())?;
+    Ok((input, params))
+}
+
+/// The outcome of a solution part, see [`PartOutput`].
+pub enum PartOutcome<'a, T> {
+    Solved(&'a T),
+    /// The part returned `None`, e.g. because it is not solved yet.
+    Unsolved,
+    /// The part returned an error, e.g. because the input could not be parsed.
+    Failed(&'a dyn Display),
+}
+
+/// Return types of solution parts: an answer, `Option<T>` to leave a part unsolved,
+/// or a `Result<T, E>` of either to report why a part failed.
+pub trait PartOutput {
+    type Answer: Display;
+
+    fn outcome(&self) -> PartOutcome<'_, Self::Answer>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    type Answer = T;
+
+    fn outcome(&self) -> PartOutcome<'_, T> {
+        match self {
+            Some(answer) => PartOutcome::Solved(answer),
+            None => PartOutcome::Unsolved,
+        }
+    }
+}
+
+impl<T: PartOutput, E: Display> PartOutput for Result<T, E> {
+    type Answer = T::Answer;
+
+    fn outcome(&self) -> PartOutcome<'_, T::Answer> {
+        match self {
+            Ok(answer) => answer.outcome(),
+            Err(e) => PartOutcome::Failed(e),
+        }
+    }
+}
+
+/// Answers are always solved. A blanket impl over `Display` would overlap with `Option<T>`,
+/// so the answer types are listed explicitly.
+macro_rules! impl_part_output {
+    ($($t:ty),*) => {
+        $(
+            impl PartOutput for $t {
+                type Answer = $t;
+
+                fn outcome(&self) -> PartOutcome<'_, $t> {
+                    PartOutcome::Solved(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_part_output!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    char,
+    String,
+    &'static str
+);
+
+/// Run a solution part with options read from the command-line and write its result record.
+pub fn run_part<I: Copy, R: PartOutput>(func: impl Fn(I) -> R, input: I, day: Day, part: u8) {
+    run_part_with(func, input, day.into(), part, &RunOptions::from_env());
+}
+
+/// Run a solution part, print its result and return it as a record.
+/// The record is written as soon as the part completes, so a parent process can tell which part is running.
+/// Errors returned by the part are printed with the day and part, and recorded as [`PartResult::error`].
+pub fn run_part_with<I: Copy, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    // every part writes its own dhat report, so that later parts do not overwrite it.
+    let dhat_file = format!("dhat-heap-{puzzle}-{part}.json");
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (result, measurement) =
+        run_timed(func, input, options.bench.as_ref(), &dhat_file, |result| {
+            print_result(&result.outcome(), &part_str, "");
+        });
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let Measurement {
+        duration,
+        stats,
+        memory,
+        allocs,
+    } = measurement;
+
+    let samples = stats.as_ref().map_or(1, |s| s.samples);
+    let outcome = result.outcome();
+
+    print_result(&outcome, &part_str, &format_duration(&duration, samples));
+
+    if let PartOutcome::Failed(e) = &outcome {
+        eprintln!(
+            "{ANSI_RED}Day {} part {part} failed: {e}{ANSI_RESET}",
+            puzzle.day
+        );
+    }
+
+    if let Some(stats) = &stats {
+        println!("{}", format_stats(stats));
+    }
+
+    if let Some(memory) = &memory {
+        println!("  {ANSI_ITALIC}{memory}{ANSI_RESET}");
+    }
+
+    if let Some(allocs) = &allocs {
+        println!("  {ANSI_ITALIC}{allocs}{ANSI_RESET}");
+    }
+
+    let (answer, error) = match &outcome {
+        PartOutcome::Solved(answer) => (Some(answer.to_string()), None),
+        PartOutcome::Unsolved => (None, None),
+        PartOutcome::Failed(e) => (None, Some(e.to_string())),
+    };
+
+    let record = PartResult {
+        day: puzzle.day,
+        part,
+        answer,
+        duration,
+        samples,
+        stats,
+        memory,
+        error,
+    };
+
+    if let Err(e) = record.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
+
+    if let PartOutcome::Solved(result) = outcome {
+        if options.submit == Some(part) {
+            match submit_result(result, puzzle, part) {
+                Ok(Some(SubmissionOutcome::Correct)) => {
+                    if let Err(e) = Answers::record(puzzle, part, &result.to_string()) {
+                        eprintln!("Failed to record answer: {e}");
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("failed to submit answer: {e}"),
+            }
+        }
     }
+
+    record
+}
+
+/// What was measured while running a solution part.
+struct Measurement {
+    /// The time of the single run, or the mean of the benchmark.
+    duration: Duration,
+    stats: Option<BenchStats>,
+    /// Heap usage of the first run, when built with `--dhat`.
+    memory: Option<MemoryStats>,
+    /// Allocations of the benchmark iterations, when built with `--alloc`.
+    allocs: Option<AllocsPerIteration>,
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether benchmark settings are passed:
+///  1. without them, the function is executed once.
+///  2. with them, the function is benched (see [`BenchConfig`]) and the mean of non-outlier samples is reported.
+///
+/// When built with `--dhat`, the heap usage of the first run is measured as well and its report is written to `dhat_file`.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    bench_config: Option<&BenchConfig>,
+    dhat_file: &str,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Measurement) {
     let timer = Instant::now();
-    let result = {
+    let (result, memory) = {
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::builder().file_name(dhat_file).build();
+        #[cfg(not(feature = "dhat-heap"))]
+        let _ = dhat_file;
+
+        let result = func(input);
+
         #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+        let memory = Some(MemoryStats::from_dhat());
+        #[cfg(not(feature = "dhat-heap"))]
+        let memory = None;
 
-        func(input)
+        (result, memory)
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let (stats, allocs) = bench_config.map_or((None, None), |config| {
+        bench(func, input, &base_time, config)
+    });
+
+    let measurement = Measurement {
+        duration: stats.as_ref().map_or(base_time, |stats| stats.mean),
+        stats,
+        memory,
+        allocs,
     };
 
-    (result, run.0, run.1)
+    (result, measurement)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> (Option<BenchStats>, Option<AllocsPerIteration>) {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..config.warmup {
+        black_box(func(black_box(input)));
+    }
+
+    let bench_iterations = config.sample_count(base_time);
+
+    // allocated up-front to not count its growth as allocations of the solution.
+    let mut timers: Vec<Duration> =
+        Vec::with_capacity(usize::try_from(bench_iterations).unwrap_or_default());
 
-    let mut timers: Vec<Duration> = vec![];
+    alloc_counter::reset();
 
     for _ in 0..bench_iterations {
         let timer = Instant::now();
@@ -67,19 +535,16 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    let allocs = alloc_counter::ENABLED.then(|| AllocCount::get().per_iteration(bench_iterations));
+
+    (BenchStats::from_samples(&timers), allocs)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_stats(stats: &BenchStats) -> String {
+    format!(
+        "  {ANSI_ITALIC}min {:.1?} · median {:.1?} · p95 {:.1?} · σ {:.1?} · {} outliers{ANSI_RESET}",
+        stats.min, stats.median, stats.p95, stats.stddev, stats.outliers
+    )
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -90,11 +555,11 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result<T: Display>(result: &PartOutcome<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        PartOutcome::Solved(result) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -114,7 +579,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        PartOutcome::Unsolved => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,44 +587,91 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        PartOutcome::Failed(_) => {
+            if is_intermediate_result {
+                print!("{part}: {ANSI_RED}✖ failed{ANSI_RESET}");
+            } else {
+                print!("\r");
+                println!("{part}: {ANSI_RED}✖ failed{ANSI_RESET}{duration_str}");
+            }
+        }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+/// Parse the `--submit <part>` argument passed to `solve`.
+fn parse_submit_arg(args: &[String]) -> Option<u8> {
+    let part_index = args.iter().position(|x| x == "--submit")? + 1;
+
+    let Some(Ok(part_submit)) = args.get(part_index).map(|x| x.parse::<u8>()) else {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+        process::exit(1);
+    };
+
+    Some(part_submit)
+}
+
+/// Submit one part of the solution and print the response of the website.
+/// Answers that were already rejected or lie outside known bounds are not sent, see `submissions`.
+/// Accepted answers are recorded in `data/answers`, see `cargo verify`.
 fn submit_result<T: Display>(
-    result: T,
-    day: Day,
+    result: &T,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+) -> Result<Option<SubmissionOutcome>, AocClientError> {
+    let client = AocClient::from_env(puzzle.year)?;
+    let answer = result.to_string();
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+    let previous = Submission::read_all_from_file(puzzle.year).unwrap_or_else(|e| {
+        eprintln!("Failed to read previous submissions: {e}");
+        vec![]
+    });
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if let Err(refusal) = submissions::check(&previous, puzzle.day, part, &answer) {
+        eprintln!("Not submitting {answer}: {refusal}");
+        return Ok(None);
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    wait_for_cooldown(&previous);
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    println!("Submitting result...");
+    let (outcome, message) = client.submit(puzzle.day, part, &answer)?;
+    print!("{message}");
+
+    if let Some(seconds) = outcome.cooldown() {
+        println!(
+            "Cooldown in effect, try again in {}.",
+            submissions::format_seconds(seconds)
+        );
+    }
+
+    let submission = Submission {
+        day: puzzle.day,
+        part,
+        answer,
+        outcome: outcome.clone(),
+        timestamp: submissions::now(),
     };
 
-    if part_submit != part {
-        return None;
+    if let Err(e) = submission.append_to_file(puzzle.year) {
+        eprintln!("Failed to record submission: {e}");
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    Ok(Some(outcome))
+}
+
+/// Block until a cooldown imposed by the website has passed, showing a countdown.
+fn wait_for_cooldown(previous: &[Submission]) {
+    if submissions::cooldown_remaining(previous, submissions::now()).is_none() {
+        return;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    while let Some(remaining) = submissions::cooldown_remaining(previous, submissions::now()) {
+        print!(
+            "\r{ANSI_ITALIC}Cooldown in effect, submitting in {}...{ANSI_RESET}  ",
+            submissions::format_seconds(remaining)
+        );
+        let _ = stdout().flush();
+        thread::sleep(Duration::from_secs(1));
+    }
+    println!();
 }
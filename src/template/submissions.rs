@@ -0,0 +1,435 @@
+/// Append-only log of submitted answers, used to guard against resubmitting known-wrong answers.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{AnswerHint, SubmissionOutcome};
+use crate::template::{data_dir, Day, Year};
+
+static SUBMISSIONS_FILE_NAME: &str = "submissions.jsonl";
+
+/// Represents a single submitted answer and the verdict of the website.
+/// Submissions are logged per year, see [`data_dir`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    /// Append the submission to the log as a single JSON line.
+    pub fn append_to_file(&self, year: Option<Year>) -> Result<(), io::Error> {
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(data_dir(year).join(SUBMISSIONS_FILE_NAME))?;
+        writeln!(file, "{line}")
+    }
+
+    /// Read all submissions from the log. If not present, returns no submissions.
+    pub fn read_all_from_file(year: Option<Year>) -> Result<Vec<Self>, String> {
+        match fs::read_to_string(data_dir(year).join(SUBMISSIONS_FILE_NAME)) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .map(|l| {
+                    let json = JsonValue::from_str(l).or(Err("not a valid JSON submission."))?;
+                    Submission::try_from(&json)
+                })
+                .collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Current time in seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reasons to not send an answer to the website.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect {
+        answer: String,
+    },
+    AlreadyRejected,
+    /// The answer is at least as high as an answer that was too high.
+    AboveTooHigh {
+        bound: i128,
+    },
+    /// The answer is at most as low as an answer that was too low.
+    BelowTooLow {
+        bound: i128,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect { answer } => {
+                write!(f, "this part was already solved with answer {answer}.")
+            }
+            Refusal::AlreadyRejected => write!(f, "this answer was already rejected."),
+            Refusal::AboveTooHigh { bound } => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which was too high."
+                )
+            }
+            Refusal::BelowTooLow { bound } => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which was too low."
+                )
+            }
+        }
+    }
+}
+
+/// Check an answer against the previous submissions of the same part.
+pub fn check(submissions: &[Submission], day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+    let previous = submissions
+        .iter()
+        .filter(|s| s.day == day && s.part == part);
+
+    let mut too_high: Option<i128> = None;
+    let mut too_low: Option<i128> = None;
+
+    for submission in previous {
+        match &submission.outcome {
+            SubmissionOutcome::Correct => {
+                return Err(Refusal::AlreadyCorrect {
+                    answer: submission.answer.clone(),
+                })
+            }
+            SubmissionOutcome::Incorrect { hint, .. } => {
+                if submission.answer == answer {
+                    return Err(Refusal::AlreadyRejected);
+                }
+
+                let Ok(value) = submission.answer.parse::<i128>() else {
+                    continue;
+                };
+
+                match hint {
+                    Some(AnswerHint::TooHigh) => {
+                        too_high = Some(too_high.map_or(value, |x| x.min(value)));
+                    }
+                    Some(AnswerHint::TooLow) => {
+                        too_low = Some(too_low.map_or(value, |x| x.max(value)));
+                    }
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        if let Some(bound) = too_high.filter(|bound| value >= *bound) {
+            return Err(Refusal::AboveTooHigh { bound });
+        }
+        if let Some(bound) = too_low.filter(|bound| value <= *bound) {
+            return Err(Refusal::BelowTooLow { bound });
+        }
+    }
+
+    Ok(())
+}
+
+/// Seconds left until the most recent cooldown imposed by the website ends, if any.
+/// Both answering too soon and wrong answers impose one. Cooldowns apply to all puzzles, so every submission is considered.
+pub fn cooldown_remaining(submissions: &[Submission], now: u64) -> Option<u64> {
+    submissions
+        .iter()
+        .filter_map(|s| Some(s.timestamp + s.outcome.cooldown()?))
+        .max()
+        .filter(|until| *until > now)
+        .map(|until| until - now)
+}
+
+/// Format seconds as e.g. `1m 05s`.
+pub fn format_seconds(seconds: u64) -> String {
+    if seconds >= 60 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn outcome_to_str(outcome: &SubmissionOutcome) -> &'static str {
+    match outcome {
+        SubmissionOutcome::Correct => "correct",
+        SubmissionOutcome::Incorrect {
+            hint: Some(AnswerHint::TooHigh),
+            ..
+        } => "too_high",
+        SubmissionOutcome::Incorrect {
+            hint: Some(AnswerHint::TooLow),
+            ..
+        } => "too_low",
+        SubmissionOutcome::Incorrect { hint: None, .. } => "incorrect",
+        SubmissionOutcome::Wait { .. } => "wait",
+        SubmissionOutcome::WrongLevel => "wrong_level",
+        SubmissionOutcome::Unknown => "unknown",
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "day".into(),
+            JsonValue::Number(f64::from(value.day.into_inner())),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(outcome_to_str(&value.outcome).into()),
+        );
+        match value.outcome {
+            // also written if the wait time could not be parsed, as it is required to read the submission back.
+            SubmissionOutcome::Wait { seconds }
+            | SubmissionOutcome::Incorrect {
+                wait_seconds: seconds,
+                ..
+            } => {
+                map.insert("wait_seconds".into(), JsonValue::Number(seconds as f64));
+            }
+            _ => {}
+        }
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<f64>())
+            .and_then(|v| Day::new(*v as u8))
+            .ok_or("Expected submission.day to be a valid day.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let wait_seconds = json
+            .get("wait_seconds")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64);
+
+        // wrong answers logged before their penalty was recorded have none, nor do waits logged without their time.
+        let incorrect = |hint: Option<AnswerHint>| SubmissionOutcome::Incorrect {
+            hint,
+            wait_seconds: wait_seconds.unwrap_or(0),
+        };
+
+        let outcome = match json.get("outcome").and_then(|v| v.get::<String>()) {
+            Some(x) if x == "correct" => SubmissionOutcome::Correct,
+            Some(x) if x == "too_high" => incorrect(Some(AnswerHint::TooHigh)),
+            Some(x) if x == "too_low" => incorrect(Some(AnswerHint::TooLow)),
+            Some(x) if x == "incorrect" => incorrect(None),
+            Some(x) if x == "wait" => SubmissionOutcome::Wait {
+                seconds: wait_seconds.unwrap_or(0),
+            },
+            Some(x) if x == "wrong_level" => SubmissionOutcome::WrongLevel,
+            Some(x) if x == "unknown" => SubmissionOutcome::Unknown,
+            _ => return Err("Expected submission.outcome to be a known outcome.".into()),
+        };
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        Ok(Submission {
+            day,
+            part: *part as u8,
+            answer: answer.clone(),
+            outcome,
+            timestamp: *timestamp as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{check, cooldown_remaining, format_seconds, Refusal, Submission};
+    use crate::{
+        day,
+        template::aoc_client::{AnswerHint, SubmissionOutcome},
+    };
+
+    fn submission(part: u8, answer: &str, outcome: SubmissionOutcome) -> Submission {
+        Submission {
+            day: day!(1),
+            part,
+            answer: answer.into(),
+            outcome,
+            timestamp: 1000,
+        }
+    }
+
+    fn get_mock_submissions() -> Vec<Submission> {
+        vec![
+            submission(
+                1,
+                "100",
+                SubmissionOutcome::Incorrect {
+                    hint: Some(AnswerHint::TooHigh),
+                    wait_seconds: 0,
+                },
+            ),
+            submission(
+                1,
+                "20",
+                SubmissionOutcome::Incorrect {
+                    hint: Some(AnswerHint::TooLow),
+                    wait_seconds: 0,
+                },
+            ),
+            submission(
+                1,
+                "abc",
+                SubmissionOutcome::Incorrect {
+                    hint: None,
+                    wait_seconds: 0,
+                },
+            ),
+            submission(2, "7", SubmissionOutcome::Correct),
+        ]
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            check(&submissions, day!(1), 1, "abc"),
+            Err(Refusal::AlreadyRejected)
+        );
+        assert_eq!(
+            check(&submissions, day!(1), 2, "8"),
+            Err(Refusal::AlreadyCorrect { answer: "7".into() })
+        );
+        assert_eq!(check(&submissions, day!(2), 1, "100"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            check(&submissions, day!(1), 1, "150"),
+            Err(Refusal::AboveTooHigh { bound: 100 })
+        );
+        assert_eq!(
+            check(&submissions, day!(1), 1, "-3"),
+            Err(Refusal::BelowTooLow { bound: 20 })
+        );
+        assert_eq!(check(&submissions, day!(1), 1, "50"), Ok(()));
+        assert_eq!(check(&submissions, day!(1), 1, "5,3"), Ok(()));
+    }
+
+    #[test]
+    fn computes_cooldowns() {
+        let mut submissions = get_mock_submissions();
+        assert_eq!(cooldown_remaining(&submissions, 1010), None);
+
+        submissions.push(submission(1, "50", SubmissionOutcome::Wait { seconds: 60 }));
+        assert_eq!(cooldown_remaining(&submissions, 1010), Some(50));
+        assert_eq!(cooldown_remaining(&submissions, 1060), None);
+
+        submissions.push(submission(
+            1,
+            "60",
+            SubmissionOutcome::Incorrect {
+                hint: None,
+                wait_seconds: 300,
+            },
+        ));
+        assert_eq!(cooldown_remaining(&submissions, 1060), Some(240));
+
+        assert_eq!(format_seconds(50), "50s");
+        assert_eq!(format_seconds(65), "1m 05s");
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let mut submissions = get_mock_submissions();
+        submissions.push(submission(1, "50", SubmissionOutcome::Wait { seconds: 60 }));
+        submissions.push(submission(
+            1,
+            "60",
+            SubmissionOutcome::Incorrect {
+                hint: Some(AnswerHint::TooHigh),
+                wait_seconds: 300,
+            },
+        ));
+
+        for s in &submissions {
+            assert_eq!(&Submission::try_from(&JsonValue::from(s)).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn roundtrips_waits_without_time() {
+        let wait = submission(1, "50", SubmissionOutcome::Wait { seconds: 0 });
+        let json = JsonValue::from(&wait);
+        assert_eq!(json["wait_seconds"], JsonValue::Number(0.0));
+        assert_eq!(Submission::try_from(&json).unwrap(), wait);
+
+        let logged =
+            r#"{ "day": 1, "part": 1, "answer": "50", "outcome": "wait", "timestamp": 1000 }"#;
+        assert_eq!(
+            Submission::try_from(&logged.parse::<JsonValue>().unwrap()).unwrap(),
+            wait
+        );
+    }
+}
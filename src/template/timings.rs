@@ -1,9 +1,9 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{bench_stats::BenchStats, data_dir, memory::MemoryStats, Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_NAME: &str = "timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -11,9 +11,79 @@ pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Heap usage of each part, only measured with `--dhat`.
+    pub part_1_memory: Option<MemoryStats>,
+    pub part_2_memory: Option<MemoryStats>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// The timing of a day that has no part benched yet.
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// Benchmark time of a part in nanoseconds.
+    /// Uses the stored stats if present, otherwise falls back to parsing the formatted time.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        let (formatted, stats) = match part {
+            1 => (&self.part_1, &self.part_1_stats),
+            2 => (&self.part_2, &self.part_2_stats),
+            _ => return None,
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        match stats {
+            Some(stats) => Some(stats.mean.as_nanos() as f64),
+            None => formatted.as_deref().and_then(parse_duration_nanos),
+        }
+    }
+
+    /// Heap usage of a part, if measured.
+    pub fn part_memory(&self, part: u8) -> Option<&MemoryStats> {
+        match part {
+            1 => self.part_1_memory.as_ref(),
+            2 => self.part_2_memory.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parse a duration formatted with `{:?}` to nanoseconds.
+fn parse_duration_nanos(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s.trim() {
+        s if s.contains("ns") => parse_to_float(s, "ns"),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
+/// Format nanoseconds like the runner formats durations, `-` if missing.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: Option<f64>) -> String {
+    nanos.map_or_else(
+        || "-".into(),
+        |x| format!("{:.1?}", Duration::from_nanos(x as u64)),
+    )
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -22,27 +92,44 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of a year.
+    pub fn store_file(&self, year: Option<Year>) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(data_dir(year).join(TIMINGS_FILE_NAME))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings from the JSON file of a year. If not present, returns empty timings.
+    pub fn read_from_file(year: Option<Year>) -> Self {
+        fs::read_to_string(data_dir(year).join(TIMINGS_FILE_NAME))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// A part that was not benched, e.g. because it timed out or failed, keeps its previous time.
+    /// The heap usage of a part is kept until it is measured again, as it is only measured with `--dhat`.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+            if let Some(previous) = self.data.iter().find(|t| t.day == timing.day) {
+                if let (None, Some(nanos)) = (timing.part_nanos(1), previous.part_nanos(1)) {
+                    timing.part_1.clone_from(&previous.part_1);
+                    timing.part_1_stats.clone_from(&previous.part_1_stats);
+                    timing.total_nanos += nanos;
+                }
+                if let (None, Some(nanos)) = (timing.part_nanos(2), previous.part_nanos(2)) {
+                    timing.part_2.clone_from(&previous.part_2);
+                    timing.part_2_stats.clone_from(&previous.part_2_stats);
+                    timing.total_nanos += nanos;
+                }
+                timing.part_1_memory = timing.part_1_memory.or(previous.part_1_memory);
+                timing.part_2_memory = timing.part_2_memory.or(previous.part_2_memory);
+            }
+            data.push(timing);
         }
 
         for timing in &self.data {
@@ -51,19 +138,44 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
+        Timings { data }
+    }
+
+    /// Merge only the heap usage of `new` into `self`, keeping the stored times.
+    /// Used for runs with `--dhat`, whose times include the overhead of the profiler.
+    pub fn merge_memory(&self, new: &Self) -> Self {
+        let mut data = self.data.clone();
+
+        for timing in &new.data {
+            let index = match data.iter().position(|t| t.day == timing.day) {
+                Some(index) => index,
+                None => {
+                    data.push(Timing::new(timing.day));
+                    data.len() - 1
+                }
+            };
+
+            let stored = &mut data[index];
+            stored.part_1_memory = timing.part_1_memory.or(stored.part_1_memory);
+            stored.part_2_memory = timing.part_2_memory.or(stored.part_2_memory);
+        }
+
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        // folded from `0.0`, as the sum of no floats is `-0.0`.
+        self.data.iter().fold(0.0, |acc, x| acc + x.total_nanos) / 1_000_000_f64
     }
 
+    /// Whether both parts of a day were benched. Parts that were aborted, e.g. with `TIMEOUT`, are not.
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && t.part_nanos(1).is_some() && t.part_nanos(2).is_some())
     }
 }
 
@@ -133,6 +245,32 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        for (key, stats) in [
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+        ] {
+            map.insert(
+                key.into(),
+                match stats {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        for (key, memory) in [
+            ("part_1_memory", &value.part_1_memory),
+            ("part_2_memory", &value.part_2_memory),
+        ] {
+            map.insert(
+                key.into(),
+                match memory {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -166,10 +304,25 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // stats and memory are optional to stay compatible with timings stored by older versions.
+        let stats = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => BenchStats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
+        let memory = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => MemoryStats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
+            part_1_memory: memory("part_1_memory")?,
+            part_2_memory: memory("part_2_memory")?,
             total_nanos,
         })
     }
@@ -187,22 +340,21 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
-                    part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -223,6 +375,28 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "mean_nanos": 1000000, "min_nanos": 900000, "median_nanos": 1000000, "p95_nanos": 1200000, "stddev_nanos": 50000, "samples": 1000, "outliers": 3 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.min.as_nanos(), 900_000);
+            assert_eq!(stats.outliers, 3);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_timings_with_memory() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_memory": { "allocations": 3, "total_bytes": 2048, "peak_bytes": 1024 }, "part_2_memory": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let memory = timing.part_memory(1).unwrap();
+            assert_eq!(memory.allocations, 3);
+            assert_eq!(memory.peak_bytes, 1024);
+            assert_eq!(timing.part_memory(2), None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -278,48 +452,93 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
-                    part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
-        fn handles_uncompleted_days() {
+        fn handles_aborted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
+                    part_1: Some("1ms".into()),
+                    part_2: Some("TIMEOUT".into()),
+                    total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_uncompleted_days() {
+            let timings = Timings {
+                data: vec![Timing::new(day!(1))],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod part_nanos {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                bench_stats::BenchStats,
+                timings::{Timing, Timings},
+            },
+        };
+
+        #[test]
+        fn parses_formatted_times() {
+            let timings = Timings::try_from(
+                r#"{ "data": [{ "day": "01", "part_1": "74.13µs", "part_2": "2.5s", "total_nanos": 0 }] }"#
+                    .to_string(),
+            )
+            .unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_nanos(1), Some(74_130_f64));
+            assert_eq!(timing.part_nanos(2), Some(2_500_000_000_f64));
+        }
+
+        #[test]
+        fn prefers_stats() {
+            let timing = Timing {
+                part_1: Some("1.0ms".into()),
+                part_1_stats: BenchStats::from_samples(&[Duration::from_nanos(900_000)]),
+                total_nanos: 900_000_f64,
+                ..Timing::new(day!(1))
+            };
+            assert_eq!(timing.part_nanos(1), Some(900_000_f64));
+            assert_eq!(timing.part_nanos(2), None);
         }
     }
 
     mod merge {
         use crate::{
             day,
+            template::memory::MemoryStats,
             template::timings::{Timing, Timings},
         };
 
@@ -329,12 +548,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -350,10 +564,10 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
+                    part_1: Some("1ms".into()),
+                    part_2: Some("2ms".into()),
+                    total_nanos: 3e+6,
+                    ..Timing::new(day!(2))
                 }],
             };
             let merged = timings.merge(&other);
@@ -361,10 +575,87 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].part_1, Some("1ms".into()));
+            assert_eq!(merged.data[1].total_nanos, 3e+6);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_times_of_parts_not_benched() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    part_1: Some("TIMEOUT".into()),
+                    part_2: Some("35ms".into()),
+                    total_nanos: 35e+6,
+                    ..Timing::new(day!(2))
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data[1].part_1, Some("30ms".into()));
+            assert_eq!(merged.data[1].part_2, Some("35ms".into()));
+            assert_eq!(merged.data[1].total_nanos, 65e+6);
+        }
+
+        #[test]
+        fn keeps_memory_until_measured_again() {
+            let mut timings = get_mock_timings();
+            let memory = MemoryStats {
+                allocations: 1,
+                total_bytes: 64,
+                peak_bytes: 64,
+            };
+            timings.data[1].part_1_memory = Some(memory);
+            timings.data[1].part_2_memory = Some(memory);
+
+            let mut other = get_mock_timings();
+            let remeasured = MemoryStats {
+                peak_bytes: 32,
+                ..memory
+            };
+            other.data[1].part_2_memory = Some(remeasured);
+
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data[1].part_1_memory, Some(memory));
+            assert_eq!(merged.data[1].part_2_memory, Some(remeasured));
+        }
+
+        #[test]
+        fn merges_only_memory() {
+            let timings = get_mock_timings();
+            let memory = MemoryStats {
+                allocations: 1,
+                total_bytes: 64,
+                peak_bytes: 64,
+            };
+
+            let other = Timings {
+                data: vec![
+                    Timing {
+                        part_1: Some("1s".into()),
+                        total_nanos: 1_000_000_000_f64,
+                        part_1_memory: Some(memory),
+                        ..Timing::new(day!(2))
+                    },
+                    Timing {
+                        part_2_memory: Some(memory),
+                        ..Timing::new(day!(3))
+                    },
+                ],
+            };
+
+            let merged = timings.merge_memory(&other);
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[1].part_1, timings.data[1].part_1);
+            assert_eq!(merged.data[1].total_nanos, timings.data[1].total_nanos);
+            assert_eq!(merged.data[1].part_1_memory, Some(memory));
+            assert_eq!(merged.data[2].day, day!(3));
+            assert_eq!(merged.data[2].part_1, None);
+            assert_eq!(merged.data[2].part_2_memory, Some(memory));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
@@ -0,0 +1,323 @@
+/// Append-only log of stored benchmark runs, used to show how a day's timings evolved.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::timings::{format_nanos, Timing, Timings};
+use crate::template::{data_dir, Day, Year, ANSI_BOLD, ANSI_RESET};
+
+static HISTORY_FILE_NAME: &str = "timings_history.jsonl";
+
+static SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Represents a single `cargo time --store` run.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Hash of the git `HEAD` at the time of the run, if available.
+    pub commit: Option<String>,
+    pub profile: String,
+    pub timings: Timings,
+}
+
+impl HistoryEntry {
+    /// Create an entry for the current time and git `HEAD`.
+    pub fn new(profile: &str, timings: Timings) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            commit: git_head(Path::new(".git")),
+            profile: profile.into(),
+            timings,
+        }
+    }
+
+    /// Append the entry to the history file of a year as a single JSON line.
+    pub fn append_to_file(&self, year: Option<Year>) -> Result<(), io::Error> {
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(data_dir(year).join(HISTORY_FILE_NAME))?;
+        writeln!(file, "{line}")
+    }
+
+    /// Read all entries from the history file of a year. If not present, returns no entries.
+    pub fn read_all_from_file(year: Option<Year>) -> Result<Vec<Self>, String> {
+        match fs::read_to_string(data_dir(year).join(HISTORY_FILE_NAME)) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .map(|l| {
+                    let json = JsonValue::from_str(l).or(Err("not a valid JSON history entry."))?;
+                    HistoryEntry::try_from(&json)
+                })
+                .collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Resolve the commit hash `HEAD` points to, following a symbolic ref if needed.
+pub fn git_head(git_dir: &Path) -> Option<String> {
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+
+    let Some(reference) = head.strip_prefix("ref:").map(str::trim) else {
+        return Some(head.to_string());
+    };
+
+    if let Ok(hash) = fs::read_to_string(git_dir.join(reference)) {
+        return Some(hash.trim().to_string());
+    }
+
+    // refs may have been moved to `packed-refs` by `git gc`.
+    fs::read_to_string(git_dir.join("packed-refs"))
+        .ok()?
+        .lines()
+        .filter_map(|l| l.split_once(' '))
+        .find(|(_, name)| *name == reference)
+        .map(|(hash, _)| hash.to_string())
+}
+
+/// Render values as a sparkline, scaled between their minimum and maximum.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn sparkline(values: &[Option<f64>]) -> String {
+    let present = values.iter().flatten();
+    let min = present.clone().copied().fold(f64::INFINITY, f64::min);
+    let max = present.copied().fold(f64::NEG_INFINITY, f64::max);
+    let top = SPARK_CHARS.len() - 1;
+
+    values
+        .iter()
+        .map(|v| match v {
+            None => ' ',
+            Some(_) if max <= min => SPARK_CHARS[top / 2],
+            Some(x) => SPARK_CHARS[(((x - min) / (max - min)) * top as f64).round() as usize],
+        })
+        .collect()
+}
+
+/// Format a unix timestamp as a `YYYY-MM-DD HH:MM` UTC date.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86400;
+    let secs = timestamp % 86400;
+
+    // civil date from days since epoch, see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        secs / 3600,
+        (secs % 3600) / 60
+    )
+}
+
+/// Print a trend table and sparklines for a single day.
+pub fn print_trend(entries: &[HistoryEntry], day: Day) {
+    let rows: Vec<(&HistoryEntry, &Timing)> = entries
+        .iter()
+        .filter_map(|e| e.timings.data.iter().find(|t| t.day == day).map(|t| (e, t)))
+        .collect();
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    if rows.is_empty() {
+        println!("No stored timings.");
+        return;
+    }
+
+    println!(
+        "{ANSI_BOLD}{:<16}  {:<7}  {:<7}  {:>10}  {:>10}{ANSI_RESET}",
+        "Date", "Commit", "Profile", "Part 1", "Part 2"
+    );
+
+    for (entry, timing) in &rows {
+        let commit = entry
+            .commit
+            .as_deref()
+            .map_or("-", |c| &c[..c.len().min(7)]);
+        println!(
+            "{:<16}  {:<7}  {:<7}  {:>10}  {:>10}",
+            format_timestamp(entry.timestamp),
+            commit,
+            entry.profile,
+            format_nanos(timing.part_nanos(1)),
+            format_nanos(timing.part_nanos(2)),
+        );
+    }
+
+    println!();
+
+    for part in [1, 2] {
+        let values: Vec<Option<f64>> = rows.iter().map(|(_, t)| t.part_nanos(part)).collect();
+        println!("Part {part}: {}", sparkline(&values));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("profile".into(), JsonValue::String(value.profile.clone()));
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.timings.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.profile to be a string.")?;
+
+        let data = json
+            .get("data")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected entry.data to be an array.")?;
+
+        Ok(HistoryEntry {
+            timestamp: timestamp as u64,
+            commit: commit.cloned(),
+            profile: profile.clone(),
+            timings: Timings {
+                data: data
+                    .iter()
+                    .map(Timing::try_from)
+                    .collect::<Result<_, _>>()?,
+            },
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use tinyjson::JsonValue;
+
+    use super::{format_timestamp, git_head, sparkline, HistoryEntry};
+    use crate::{day, template::timings::Timing, template::timings::Timings};
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_733_011_200), "2024-12-01 00:00");
+        assert_eq!(format_timestamp(1_709_210_096), "2024-02-29 12:34");
+    }
+
+    #[test]
+    fn renders_sparklines() {
+        assert_eq!(sparkline(&[Some(1.0), Some(8.0), None, Some(4.5)]), "▁█ ▅");
+        assert_eq!(sparkline(&[Some(2.0), Some(2.0)]), "▄▄");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn resolves_git_head() {
+        let dir = env::temp_dir().join(format!("aoc-git-head-{}", std::process::id()));
+        fs::create_dir_all(dir.join("refs/heads")).unwrap();
+
+        fs::write(dir.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        fs::write(
+            dir.join("packed-refs"),
+            "# pack-refs\nabc123 refs/heads/main\n",
+        )
+        .unwrap();
+        assert_eq!(git_head(&dir), Some("abc123".into()));
+
+        fs::write(dir.join("refs/heads/main"), "def456\n").unwrap();
+        assert_eq!(git_head(&dir), Some("def456".into()));
+
+        fs::write(dir.join("HEAD"), "0123abcd\n").unwrap();
+        assert_eq!(git_head(&dir), Some("0123abcd".into()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = HistoryEntry {
+            timestamp: 1_733_011_200,
+            commit: Some("abc123".into()),
+            profile: "release".into(),
+            timings: Timings {
+                data: vec![Timing {
+                    part_1: Some("1.0ms".into()),
+                    total_nanos: 1_000_000_f64,
+                    ..Timing::new(day!(3))
+                }],
+            },
+        };
+
+        let parsed = HistoryEntry::try_from(&JsonValue::from(&entry)).unwrap();
+        assert_eq!(parsed.timestamp, entry.timestamp);
+        assert_eq!(parsed.commit, entry.commit);
+        assert_eq!(parsed.profile, "release");
+        assert_eq!(parsed.timings.data[0].day, day!(3));
+        assert_eq!(parsed.timings.data[0].part_nanos(1), Some(1_000_000_f64));
+    }
+}
@@ -0,0 +1,180 @@
+/// Polls the files of a day for modifications, see `cargo solve --watch`.
+///
+/// Plain filesystem polling keeps this free of platform-specific watchers and external binaries.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::template::{
+    data_dir, inputs::InputSource, part_result::PartResult, PuzzleId, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Files that trigger a re-run of a day: its solution, the library, and its inputs, examples and their sidecars.
+pub fn watched_paths(puzzle: PuzzleId, input: Option<&InputSource>) -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from(puzzle.bin_path())];
+
+    // the library, i.e. every source file outside of `src/bin`.
+    collect_files(Path::new("src"), &mut paths, &|path| {
+        path.extension().is_some_and(|ext| ext == "rs")
+    });
+
+    let prefix = puzzle.day.to_string();
+    for folder in ["inputs", "examples"] {
+        collect_files(&data_dir(puzzle.year).join(folder), &mut paths, &|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+        });
+    }
+
+    for path in input
+        .and_then(|input| input.paths().ok())
+        .unwrap_or_default()
+    {
+        paths.push(path.with_extension("toml"));
+        paths.push(path);
+    }
+
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>, filter: &dyn Fn(&Path) -> bool) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name != "bin") {
+                collect_files(&path, paths, filter);
+            }
+        } else if filter(&path) {
+            paths.push(path);
+        }
+    }
+}
+
+/// Modification times of a set of files, `None` for missing files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot(Vec<(PathBuf, Option<SystemTime>)>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        Self(
+            paths
+                .iter()
+                .map(|path| {
+                    let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+                    (path.clone(), modified)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Describe every answer of a run compared to the previous run, e.g. `Part 1: 42 → 43`.
+/// Runs against several inputs contain a record per input and part, these are matched by their order.
+pub fn format_changes(previous: &[PartResult], current: &[PartResult]) -> Vec<String> {
+    let nth = |records: &[PartResult], index: usize| {
+        let part = records[index].part;
+        records[..index].iter().filter(|r| r.part == part).count()
+    };
+
+    let has_several_inputs = current
+        .first()
+        .is_some_and(|first| current.iter().filter(|r| r.part == first.part).count() > 1);
+
+    current
+        .iter()
+        .enumerate()
+        .map(|(i, record)| {
+            let n = nth(current, i);
+            let label = if has_several_inputs {
+                format!("Input #{} part {}", n + 1, record.part)
+            } else {
+                format!("Part {}", record.part)
+            };
+
+            let answer = record.answer.as_deref().unwrap_or("✖");
+
+            let before = (0..previous.len())
+                .find(|&j| previous[j].part == record.part && nth(previous, j) == n)
+                .map(|j| previous[j].answer.as_deref().unwrap_or("✖"));
+
+            match before {
+                Some(before) if before == answer => format!("{label}: {answer} (unchanged)"),
+                Some(before) => format!("{label}: {before} → {ANSI_BOLD}{answer}{ANSI_RESET}"),
+                None => format!("{label}: {ANSI_BOLD}{answer}{ANSI_RESET}"),
+            }
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use super::{format_changes, Snapshot};
+    use crate::day;
+    use crate::template::{part_result::PartResult, ANSI_BOLD, ANSI_RESET};
+
+    fn record(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            answer: answer.map(String::from),
+            ..PartResult::new(day!(1), part)
+        }
+    }
+
+    #[test]
+    fn formats_changed_answers() {
+        let previous = [record(1, Some("42")), record(2, None)];
+        let current = [record(1, Some("42")), record(2, Some("7"))];
+
+        assert_eq!(
+            format_changes(&previous, &current),
+            vec![
+                "Part 1: 42 (unchanged)".to_string(),
+                format!("Part 2: ✖ → {ANSI_BOLD}7{ANSI_RESET}"),
+            ]
+        );
+
+        assert_eq!(
+            format_changes(&[], &current[..1]),
+            vec![format!("Part 1: {ANSI_BOLD}42{ANSI_RESET}")]
+        );
+    }
+
+    #[test]
+    fn matches_answers_of_several_inputs_by_order() {
+        let previous = [record(1, Some("1")), record(1, Some("2"))];
+        let current = [record(1, Some("1")), record(1, Some("3"))];
+
+        assert_eq!(
+            format_changes(&previous, &current),
+            vec![
+                "Input #1 part 1: 1 (unchanged)".to_string(),
+                format!("Input #2 part 1: 2 → {ANSI_BOLD}3{ANSI_RESET}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_modifications() {
+        let path = env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let paths = [path.clone(), PathBuf::from("data/inputs/missing.txt")];
+
+        let before = Snapshot::take(&paths);
+        fs::write(&path, "42").unwrap();
+        let after = Snapshot::take(&paths);
+        assert_ne!(before, after);
+        assert_eq!(after, Snapshot::take(&paths));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,188 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::template::Day;
+
+/// A valid year of advent (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(2015..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The default year, as configured by the `AOC_YEAR` env var.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year number of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies the puzzle of a day, optionally in a specific year.
+///
+/// Without a year, files use the single-year layout (e.g. `data/inputs/01.txt` and `src/bin/01.rs`).
+/// With a year, they are kept apart per year (e.g. `data/2023/inputs/01.txt` and `src/bin/2023-01.rs`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Option<Year>,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Option<Year>, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Path of the day's file in a data folder, e.g. `data/2023/inputs/01.txt`.
+    pub fn data_path(&self, folder: &str) -> PathBuf {
+        data_dir(self.year)
+            .join(folder)
+            .join(format!("{}.txt", self.day))
+    }
+
+    /// Name of the solution binary, e.g. `2023-01`.
+    pub fn bin_name(&self) -> String {
+        match self.year {
+            Some(year) => format!("{year}-{}", self.day),
+            None => self.day.to_string(),
+        }
+    }
+
+    /// Path of the solution binary's source file, e.g. `./src/bin/2023-01.rs`.
+    pub fn bin_path(&self) -> String {
+        format!("./src/bin/{}.rs", self.bin_name())
+    }
+}
+
+impl From<Day> for PuzzleId {
+    fn from(day: Day) -> Self {
+        Self::new(None, day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.bin_name())
+    }
+}
+
+/// The data folder of a year, `data` for the single-year layout.
+pub fn data_dir(year: Option<Year>) -> PathBuf {
+    match year {
+        Some(year) => PathBuf::from("data").join(year.to_string()),
+        None => PathBuf::from("data"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year number `",
+                $year,
+                "`, expecting a value of 2015 or later"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{PuzzleId, Year};
+    use crate::day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2023".parse::<Year>().unwrap(), year!(2023));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn resolves_single_year_paths() {
+        let puzzle = PuzzleId::from(day!(1));
+        assert_eq!(puzzle.bin_name(), "01");
+        assert_eq!(puzzle.bin_path(), "./src/bin/01.rs");
+        assert_eq!(
+            puzzle.data_path("inputs"),
+            PathBuf::from("data/inputs/01.txt")
+        );
+    }
+
+    #[test]
+    fn resolves_per_year_paths() {
+        let puzzle = PuzzleId::new(Some(year!(2023)), day!(7));
+        assert_eq!(puzzle.bin_name(), "2023-07");
+        assert_eq!(puzzle.bin_path(), "./src/bin/2023-07.rs");
+        assert_eq!(
+            puzzle.data_path("examples"),
+            PathBuf::from("data/2023/examples/07.txt")
+        );
+    }
+}